@@ -0,0 +1,13 @@
+use clap::Parser;
+use lunesrs::cli::Cli;
+
+/// Print the JSON of the command, or `{"error": ...}` to stderr and exit with 1
+fn main() {
+    match Cli::parse().run() {
+        Ok(json) => println!("{:#}", json),
+        Err(e) => {
+            eprintln!("{:#}", serde_json::json!({ "error": e.to_string() }));
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::io::Read;
+
+use clap::{Args, Parser, Subcommand};
+use serde_json::{json, Value};
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::transactions::fee::{suggest_fee_with, SmartScripts};
+use crate::transactions::json::with_chain;
+use crate::transactions::parser::{SignedTransaction, TransactionKind};
+use crate::transactions::{Transaction, SIGNATURE_LENGTH};
+use crate::utils::base58::{try_b58_to_vec, vec_to_b58};
+use crate::wallet::account::Account;
+use crate::wallet::address::Address;
+use crate::wallet::mnemonic::{generate_seed_phrase, SEED_PHRASE_LENGTH};
+use crate::wallet::secret::{PrivateKey, Seed};
+use crate::wallet::signatures::validate_signature;
+use crate::wallet::PUBLIC_KEY_LENGTH;
+
+/**
+# Cli
+## Arguments of the `lunes` binary
+
+- Build it with `--features cli`, every command prints a JSON object
+- Secrets are read from `--seed` and `--private-key` or from `LUNES_SEED` and `LUNES_PRIVATE_KEY`,
+  prefer the variables to keep them out of the process list
+- Keys, signatures and binary messages are encoded in base58
+
+## In Shell 💻
+
+```sh
+lunes seed new
+lunes --network testnet account derive --nonce 2
+lunes address validate 37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj
+lunes sign "Lunes" | jq -r .signature
+lunes tx build transfer.json > signed.json
+```
+
+## In Rust 🤝
+
+```rust
+use clap::Parser;
+use lunesrs::cli::Cli;
+
+let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let cli = Cli::try_parse_from(["lunes", "account", "derive", "--seed", seed, "--nonce", "0"]).unwrap();
+let json = cli.run().unwrap();
+
+assert_eq!(json["address"], "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+assert_eq!(json["nonce"], 0);
+```
+*/
+#[derive(Debug, Parser)]
+#[command(
+    name = "lunes",
+    version,
+    about = "Wallet tool for Lunes Blockchain",
+    long_about = None
+)]
+pub struct Cli {
+    /// `mainnet`, `testnet` or the chain byte of a private network
+    #[arg(long, global = true, default_value = "mainnet")]
+    pub network: Network,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Seed phrases
+    #[command(subcommand)]
+    Seed(SeedCommand),
+    /// Accounts of a seed or a private key
+    #[command(subcommand)]
+    Account(AccountCommand),
+    /// Addresses encoded in base58
+    #[command(subcommand)]
+    Address(AddressCommand),
+    /// Sign a message with the account
+    Sign {
+        #[command(flatten)]
+        key: KeyArgs,
+        #[command(flatten)]
+        message: MessageArgs,
+    },
+    /// Check the signature of a message
+    Verify {
+        /// Public key of the signer
+        #[arg(long)]
+        public_key: String,
+        #[arg(long)]
+        signature: String,
+        #[command(flatten)]
+        message: MessageArgs,
+    },
+    /// Encode and decode base58
+    #[command(subcommand)]
+    Base58(Base58Command),
+    /// Transactions in the JSON of the node
+    #[command(subcommand)]
+    Tx(TxCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SeedCommand {
+    /// Generate a random seed phrase
+    New {
+        #[arg(long, default_value_t = SEED_PHRASE_LENGTH)]
+        words: usize,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AccountCommand {
+    /// Address and public key of the account
+    Derive {
+        #[command(flatten)]
+        key: KeyArgs,
+        /// Print the private key too
+        #[arg(long)]
+        show_private_key: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AddressCommand {
+    /// Check the length, version, checksum and chain of an address
+    Validate { address: String },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Base58Command {
+    /// Encode a text in base58
+    Encode { text: String },
+    /// Decode base58 to bytes, and to a text if it is UTF-8
+    Decode { base58: String },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TxCommand {
+    /// Sign an unsigned transaction and print it ready to broadcast
+    ///
+    /// `senderPublicKey`, `timestamp` and `fee` are filled when missing, the fee is the minimum of the network
+    Build {
+        #[command(flatten)]
+        key: KeyArgs,
+        /// JSON of the transaction, a file or `-` for stdin
+        transaction: String,
+    },
+}
+
+/// Account that signs, from a seed and a nonce or from a private key
+#[derive(Debug, Args)]
+pub struct KeyArgs {
+    /// Seed phrase of the account
+    #[arg(
+        long,
+        env = "LUNES_SEED",
+        hide_env_values = true,
+        required_unless_present = "private_key"
+    )]
+    pub seed: Option<String>,
+    /// Nonce of the account of the seed
+    #[arg(long, default_value_t = 0)]
+    pub nonce: u32,
+    /// Private key encoded in base58, ignored when there is a seed
+    #[arg(long, env = "LUNES_PRIVATE_KEY", hide_env_values = true)]
+    pub private_key: Option<String>,
+}
+
+/// Message to sign or verify
+#[derive(Debug, Args)]
+pub struct MessageArgs {
+    pub message: String,
+    /// The message is base58 instead of a text
+    #[arg(long)]
+    pub base58: bool,
+}
+
+impl Cli {
+    /// Run the command and return the JSON it prints
+    pub fn run(&self) -> Result<Value, LunesError> {
+        let network = self.network;
+
+        match &self.command {
+            Command::Seed(SeedCommand::New { words }) => {
+                Ok(json!({ "seed": generate_seed_phrase(*words) }))
+            }
+            Command::Account(AccountCommand::Derive {
+                key,
+                show_private_key,
+            }) => {
+                let account = key.account(network)?;
+                let mut json = account_json(&account);
+                if *show_private_key {
+                    json["privateKey"] = account.private_key_b58().into();
+                }
+                Ok(json)
+            }
+            Command::Address(AddressCommand::Validate { address }) => {
+                let checked = Address::from_b58(address.clone()).and_then(|address| {
+                    address.check_network(network)?;
+                    Ok(address)
+                });
+                Ok(match checked {
+                    Ok(address) => json!({
+                        "address": address.to_string(),
+                        "valid": true,
+                        "network": address.network().to_string(),
+                        "chainId": address.chain_id(),
+                    }),
+                    Err(e) => json!({
+                        "address": address,
+                        "valid": false,
+                        "error": e.to_string(),
+                    }),
+                })
+            }
+            Command::Sign { key, message } => {
+                let account = key.account(network)?;
+                let signature = account.sign_message(message.bytes()?)?;
+                let mut json = account_json(&account);
+                json["signature"] = vec_to_b58(signature).into();
+                Ok(json)
+            }
+            Command::Verify {
+                public_key,
+                signature,
+                message,
+            } => {
+                let public_key = try_b58_to_vec(public_key.clone())?;
+                let signature = try_b58_to_vec(signature.clone())?;
+                if public_key.len() != PUBLIC_KEY_LENGTH as usize {
+                    return Err(LunesError::InvalidKeyLength(public_key.len()));
+                }
+                if signature.len() != SIGNATURE_LENGTH as usize {
+                    return Err(LunesError::InvalidSignatureLength(signature.len()));
+                }
+
+                Ok(json!({
+                    "valid": validate_signature(public_key, message.bytes()?, signature),
+                }))
+            }
+            Command::Base58(Base58Command::Encode { text }) => {
+                Ok(json!({ "base58": vec_to_b58(text.as_bytes().to_vec()) }))
+            }
+            Command::Base58(Base58Command::Decode { base58 }) => {
+                let bytes = try_b58_to_vec(base58.clone())?;
+                Ok(json!({
+                    "text": String::from_utf8(bytes.clone()).ok(),
+                    "bytes": bytes,
+                }))
+            }
+            Command::Tx(TxCommand::Build { key, transaction }) => {
+                let account = key.account(network)?;
+                build_transaction(&account, &read_input(transaction)?)
+            }
+        }
+    }
+}
+
+impl KeyArgs {
+    /// Account of the seed or of the private key, fail if there is none
+    pub fn account(&self, network: Network) -> Result<Account, LunesError> {
+        match (&self.seed, &self.private_key) {
+            (Some(seed), _) => Ok(Account::from_secret_seed(
+                Seed::from(seed.as_str()).validate()?,
+                self.nonce,
+                network,
+            )),
+            (None, Some(private_key)) => Account::from_secret_key(
+                PrivateKey::from(try_b58_to_vec(private_key.clone())?),
+                network,
+            ),
+            (None, None) => Err(LunesError::MissingKey),
+        }
+    }
+}
+
+impl MessageArgs {
+    fn bytes(&self) -> Result<Vec<u8>, LunesError> {
+        if self.base58 {
+            try_b58_to_vec(self.message.clone())
+        } else {
+            Ok(self.message.as_bytes().to_vec())
+        }
+    }
+}
+
+fn account_json(account: &Account) -> Value {
+    json!({
+        "address": account.address_b58(),
+        "publicKey": account.public_key_b58(),
+        "nonce": account.nonce(),
+        "network": account.network().to_string(),
+        "chainId": account.chain_id(),
+    })
+}
+
+/// Text of an argument that is inline JSON, a file or `-` for stdin
+fn read_input(input: &str) -> Result<String, LunesError> {
+    let io_error = |e: std::io::Error| LunesError::Json(format!("{}: {}", input, e));
+
+    if input == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(io_error)?;
+        Ok(text)
+    } else if input.trim_start().starts_with('{') {
+        Ok(input.to_string())
+    } else {
+        std::fs::read_to_string(input).map_err(io_error)
+    }
+}
+
+/// Fill the sender, the timestamp and the fee, sign and serialize the transaction
+fn build_transaction(account: &Account, json: &str) -> Result<Value, LunesError> {
+    let mut json: Value =
+        serde_json::from_str(json).map_err(|e| LunesError::Json(e.to_string()))?;
+    let fields = json
+        .as_object_mut()
+        .ok_or_else(|| LunesError::Json("transaction is not a JSON object".to_string()))?;
+
+    fields
+        .entry("senderPublicKey")
+        .or_insert_with(|| account.public_key_b58().into());
+    fields.entry("timestamp").or_insert_with(|| {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        (now.as_millis() as u64).into()
+    });
+
+    let missing_fee = !fields.contains_key("fee");
+    if missing_fee {
+        fields.insert("fee".to_string(), 0.into());
+    }
+
+    let parse = |json: Value| -> Result<TransactionKind, LunesError> {
+        serde_json::from_value(with_chain(json, account.network()))
+            .map_err(|e| LunesError::Json(e.to_string()))
+    };
+    let mut transaction = parse(json.clone())?;
+    if missing_fee {
+        let fees = account.network().fee_defaults();
+        json["fee"] = suggest_fee_with(&transaction, &fees, SmartScripts::default()).into();
+        transaction = parse(json)?;
+    }
+    if transaction.sender_public_key() != account.public_key() {
+        return Err(LunesError::Json(
+            "senderPublicKey is not the public key of the account".to_string(),
+        ));
+    }
+    let signature = account.sign_message(transaction.body_bytes()?)?;
+
+    serde_json::to_value(SignedTransaction {
+        transaction,
+        signature,
+    })
+    .map_err(|e| LunesError::Json(e.to_string()))
+}
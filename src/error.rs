@@ -0,0 +1,205 @@
+use wasm_bindgen::prelude::{JsError, JsValue};
+
+#[cfg(feature = "node")]
+use crate::node::NodeError;
+use crate::transactions::validation::Violations;
+use crate::transactions::{TransactionError, MAX_AMOUNT, SIGNATURE_LENGTH};
+use crate::utils::serialize::MAX_ARRAY_LENGTH;
+use crate::wallet::mnemonic::UnknownWord;
+use crate::wallet::{ADDRESS_LENGTH, ADDRESS_VERSION, PRIVATE_KEY_LENGTH};
+
+/**
+# Lunes Error
+## Reasons a function of lunesrs can fail
+
+- Returned by the `try_*` functions instead of a panic
+- Thrown to JavaScript like an `Error` with the message of `Display`
+- Non exhaustive, variants depend on the enabled features like `node`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+try {
+    wasm.base58ToArray("0OIl")
+} catch (e) {
+    e instanceof Error === true
+}
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::error::LunesError;
+use lunesrs::utils::base58::try_b58_to_vec;
+
+assert_eq!(true, matches!(try_b58_to_vec("0OIl".to_string()), Err(LunesError::InvalidBase58(_))));
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LunesError {
+    /// String is not valid base58
+    InvalidBase58(String),
+    /// Hasher could not be built
+    Hash(String),
+    /// Address must have 26 bytes
+    InvalidAddressLength(usize),
+    /// Address version byte is not 1 or 11
+    InvalidAddressVersion(u8),
+    /// Address chain byte is not the byte of the chain id
+    InvalidAddressChain(u8),
+    /// Address checksum does not match the hash of the address
+    InvalidAddressChecksum,
+    /// Array must have at most 65535 bytes to be prefixed by its length
+    ArrayTooLong(usize),
+    /// Private and public keys must have 32 bytes
+    InvalidKeyLength(usize),
+    /// Signature of `fast_signature` must have 64 bytes
+    InvalidSignatureLength(usize),
+    /// Watch-only account has no private key to sign
+    WatchOnly,
+    /// Neither a seed nor a private key was given to sign
+    MissingKey,
+    /// Seed phrase has no words
+    EmptySeedPhrase,
+    /// Seed phrase has words that are not in the word list
+    InvalidSeedPhrase(Vec<UnknownWord>),
+    /// Keystore is malformed or uses an unsupported version, kdf or cipher
+    InvalidKeystore(String),
+    /// Password of the keystore is wrong or the keystore was changed
+    KeystoreDecryption,
+    /// Random bytes are not available
+    Random(String),
+    /// Network is not `mainnet`, `testnet` or a chain byte
+    InvalidNetwork(String),
+    /// Vanity pattern is not base58, does not compile or can not match an address
+    InvalidVanityPattern(String),
+    /// JSON is not a valid transaction
+    Json(String),
+    /// Text is not a decimal amount or the decimals are more than 8
+    InvalidAmount(String),
+    /// Amount is negative or greater than `MAX_AMOUNT`, the limit of the node
+    AmountOverflow,
+    /// Amounts with different decimals can not be added or subtracted
+    MismatchedDecimals(u8, u8),
+    /// Transaction can not be built or parsed
+    Transaction(TransactionError),
+    /// Transaction breaks rules of the node, see `Validate`
+    Violations(Violations),
+    /// Call to a node failed
+    #[cfg(feature = "node")]
+    Node(NodeError),
+}
+
+impl std::fmt::Display for LunesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LunesError::InvalidBase58(e) => write!(f, "invalid base58: {}", e),
+            LunesError::Hash(e) => write!(f, "hash error: {}", e),
+            LunesError::InvalidAddressLength(len) => {
+                write!(f, "address must have {} bytes, got {}", ADDRESS_LENGTH, len)
+            }
+            LunesError::InvalidAddressVersion(byte) => write!(
+                f,
+                "address version must be one of {:?}, got {}",
+                ADDRESS_VERSION, byte
+            ),
+            LunesError::InvalidAddressChain(byte) => {
+                write!(
+                    f,
+                    "address belongs to another chain, got chain byte {}",
+                    byte
+                )
+            }
+            LunesError::InvalidAddressChecksum => write!(f, "address checksum does not match"),
+            LunesError::ArrayTooLong(len) => write!(
+                f,
+                "array must have at most {} bytes, got {}",
+                MAX_ARRAY_LENGTH, len
+            ),
+            LunesError::InvalidKeyLength(len) => {
+                write!(f, "key must have {} bytes, got {}", PRIVATE_KEY_LENGTH, len)
+            }
+            LunesError::InvalidSignatureLength(len) => write!(
+                f,
+                "signature must have {} bytes, got {}",
+                SIGNATURE_LENGTH, len
+            ),
+            LunesError::WatchOnly => write!(f, "watch-only account can not sign"),
+            LunesError::MissingKey => write!(f, "a seed or a private key is needed to sign"),
+            LunesError::EmptySeedPhrase => write!(f, "seed phrase has no words"),
+            LunesError::InvalidSeedPhrase(words) => write!(
+                f,
+                "unknown words in seed phrase: {}",
+                words
+                    .iter()
+                    .map(|word| word.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            LunesError::InvalidKeystore(e) => write!(f, "invalid keystore: {}", e),
+            LunesError::KeystoreDecryption => {
+                write!(f, "wrong password or the keystore was changed")
+            }
+            LunesError::Random(e) => write!(f, "random bytes are not available: {}", e),
+            LunesError::InvalidNetwork(network) => write!(
+                f,
+                "unknown network {:?}, expected mainnet, testnet or a chain byte",
+                network
+            ),
+            LunesError::InvalidVanityPattern(e) => write!(f, "invalid vanity pattern: {}", e),
+            LunesError::Json(e) => write!(f, "invalid JSON: {}", e),
+            LunesError::InvalidAmount(e) => write!(f, "invalid amount: {}", e),
+            LunesError::AmountOverflow => {
+                write!(f, "amount must be between 0 and {} units", MAX_AMOUNT)
+            }
+            LunesError::MismatchedDecimals(left, right) => {
+                write!(f, "amounts have different decimals, {} and {}", left, right)
+            }
+            LunesError::Transaction(e) => write!(f, "{}", e),
+            LunesError::Violations(e) => write!(f, "{}", e),
+            #[cfg(feature = "node")]
+            LunesError::Node(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for LunesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LunesError::Transaction(e) => Some(e),
+            LunesError::Violations(e) => Some(e),
+            #[cfg(feature = "node")]
+            LunesError::Node(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransactionError> for LunesError {
+    fn from(e: TransactionError) -> Self {
+        LunesError::Transaction(e)
+    }
+}
+
+impl From<Violations> for LunesError {
+    fn from(e: Violations) -> Self {
+        LunesError::Violations(e)
+    }
+}
+
+#[cfg(feature = "node")]
+impl From<NodeError> for LunesError {
+    fn from(e: NodeError) -> Self {
+        LunesError::Node(e)
+    }
+}
+
+impl From<LunesError> for JsValue {
+    /// Throw the error to JavaScript like an `Error`
+    fn from(e: LunesError) -> Self {
+        JsError::new(&e.to_string()).into()
+    }
+}
@@ -27,21 +27,146 @@
 //!         - validate_signature `(Vec<u32>, Vec<u32>, Vec<u32>)` -> `Vec<u32>`
 //!         - full_signature `(Vec<u32>, Vec<u32>)` -> `Vec<u32>`
 //!         - fast_signature `(Vec<u32>, Vec<u32>)` -> `Vec<u32>`
+//!         - full_signature_of `(&PrivateKey, Vec<u8>)` -> `Vec<u8>`
+//!         - fast_signature_of `(&PrivateKey, Vec<u8>)` -> `Vec<u8>`
 //!         - validate_address `(u8, Vec<u8>)` -> `bool`
+//!         - try_validate_address `(u8, Vec<u8>)` -> `Result<(), LunesError>`
+//!
+//!      - Account
+//!         - Account `from_seed` `from_private_key` `from_public_key`
+//!         - Account `from_secret_seed` `from_secret_key` `secret_seed` `secret_key` `network`
+//!         - Account `sign_message` `verify_message` `sign_transaction`
+//!         - derive_accounts `(impl Into<Seed>, impl Into<Network>, u32)` -> `Accounts`
+//!         - derive_account_range `(String, u8, u32, u32)` -> `Vec<Account>`
+//!         - discover_accounts `(impl Into<Seed>, impl Into<Network>, u32, FnMut(&Account) -> Result<bool, E>)` -> `Result<Vec<Account>, E>`
+//!
+//!      - Address
+//!         - Address `from_bytes` `from_b58` `from_public_key` `FromStr` `Display`
+//!         - Address `version` `chain_byte` `chain_id` `public_key_hash` `checksum` `check_chain`
+//!         - Address `new` `network` `check_network`
 //!
 //!      - Wallet
 //!         - to_address `(u8, u8, Vec<u8>)` -> `Vec<u8>`
 //!         - hidden_seed `(u32, String)` -> `Vec<u8>`
+//!         - hidden_seed_of `(u32, &Seed)` -> `Zeroizing<Vec<u8>>`
+//!         - try_hidden_seed `(u32, String)` -> `Result<Vec<u8>, LunesError>`
 //!         - to_private_key `Vec<u8>` -> `Vec<u8>`
 //!         - to_public_key `Vec<u8>` -> `Vec<u8>`
+//!         - public_key_of `&PrivateKey` -> `Vec<u8>`
+//!
+//!      - Secret
+//!         - Seed `expose_secret` `validate` `hidden_seed` `private_key`
+//!         - PrivateKey `from_hidden_seed` `expose_secret` `public_key` `sign` `full_sign`
+//!
+//!      - Vanity (feature `vanity`)
+//!         - VanityPattern `Prefix(String)` | `Suffix(String)` | `Regex(Regex)` `is_match` `difficulty`
+//!         - search_vanity `(&VanityPattern, &VanityOptions, FnMut(&VanityProgress) -> bool)` -> `Result<Option<VanityMatch>, LunesError>`
+//!         - find_vanity_account `(String, u8, u64)` -> `Result<Option<Account>, LunesError>`
+//!
+//!      - Keystore (feature `keystore`)
+//!         - Keystore `version` `kind` `address` `public_key` `chain_id` `nonce` `crypto`
+//!         - encrypt_keystore `(&Account, String)` -> `Result<String, LunesError>`
+//!         - decrypt_keystore `(String, String)` -> `Result<Account, LunesError>`
+//!
+//!      - Mnemonic
+//!         - WORD_LIST `[&str; 2048]`
+//!         - generate_seed_phrase `usize` -> `String`
+//!         - normalize_seed_phrase `&str` -> `String`
+//!         - validate_seed_phrase `String` -> `Result<String, LunesError>`
+//!
+//! - **Transactions**
+//!     - Transaction `body_bytes` `signed_bytes` `id_bytes` `id`
+//!         - body_from_signed_bytes `Vec<u8>` -> `Result<Vec<u8>, TransactionError>`
+//!         - transaction_id `Vec<u8>` -> `Result<String, LunesError>`
+//!
+//!     - Parser
+//!         - SignedTransaction `id` `signed_bytes` `verify`
+//!         - parse_transaction `Vec<u8>` -> `Result<SignedTransaction, TransactionError>`
+//!         - verify_transaction `Vec<u8>` -> `bool`
+//!
+//!     - Transfer
+//!         - TransferTransaction `sign`
+//!         - sign_transfer `(Vec<u8>, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!
+//!     - Issue, Reissue and Burn
+//!         - IssueTransaction `sign`
+//!         - ReissueTransaction `sign`
+//!         - BurnTransaction `sign`
+//!         - sign_issue `(Vec<u8>, Vec<u8>, String, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_reissue `(Vec<u8>, Vec<u8>, Vec<u8>, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_burn `(Vec<u8>, Vec<u8>, Vec<u8>, ...)` -> `Result<Vec<u8>, LunesError>`
+//!
+//!     - Lease
+//!         - LeaseTransaction `sign`
+//!         - LeaseCancelTransaction `sign`
+//!         - sign_lease `(Vec<u8>, impl Into<Network>, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_lease_js `(Vec<u8>, u8, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_lease_cancel `(Vec<u8>, Vec<u8>, Vec<u8>, ...)` -> `Result<Vec<u8>, LunesError>`
+//!
+//!     - Mass Transfer
+//!         - MassTransferTransaction `sign`
+//!         - sign_mass_transfer `(Vec<u8>, impl Into<Network>, Vec<u8>, Vec<u8>, Vec<String>, Vec<u64>, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_mass_transfer_js `(Vec<u8>, u8, Vec<u8>, Vec<u8>, Vec<String>, Vec<u64>, ...)` -> `Result<Vec<u8>, LunesError>`
+//!
+//!     - Alias
+//!         - Alias `new` `network` `validate` `to_bytes`
+//!         - CreateAliasTransaction `sign`
+//!         - Recipient `Address(Vec<u8>)` | `Alias(Alias)` `validate` `to_bytes`
+//!         - sign_create_alias `(Vec<u8>, impl Into<Network>, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!         - sign_create_alias_js `(Vec<u8>, u8, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>`
+//!
+//!     - Data
+//!         - DataEntry `key` `DataValue::{Integer, Boolean, Binary, String}`
+//!         - DataTransaction `from_body_bytes` `sign`
+//!         - sign_data `(Vec<u8>, Vec<u8>, String, ...)` -> `Result<Vec<u8>, LunesError>` (feature `serde`)
+//!
+//!     - Fee
+//!         - Fee `required_fee` for every transaction and TransactionKind
+//!         - SmartScripts `account` `assets` `extra_fee`
+//!         - suggest_fee `&impl Fee` -> `u64`
+//!         - suggest_fee_with `(&impl Fee, &FeeDefaults, SmartScripts)` -> `u64`
+//!
+//!     - Validation
+//!         - Validate `validate` `violations` for every transaction and TransactionKind
+//!         - Rules `new` `network` `now` `max_past` `max_future`
+//!         - validate_transaction `(Vec<u8>, impl Into<Network>, u64)` -> `Result<(), LunesError>`
+//!         - validate_transaction_js `(Vec<u8>, u8, u64)` -> `Result<(), LunesError>`
+//!
+//!     - JSON (feature `serde`)
+//!         - `Serialize` and `Deserialize` in the JSON of the node for every transaction
+//!         - SignedTransaction `from_json` with the network of the alias chain
+//!         - transaction_to_json `Vec<u8>` -> `Result<String, LunesError>`
+//!         - transaction_from_json `(String, u8)` -> `Result<Vec<u8>, LunesError>`
+//!
+//! - **Network**
+//!     - Network `Mainnet` | `Testnet` | `Custom { byte }`
+//!         - Network `from_chain_byte` `from_chain_id` `chain_byte` `chain_id` `node_urls` `fee_defaults`
+//!         - chain_id_of `String` -> `Result<u8, LunesError>`
+//!
+//! - **Node** (feature `node`)
+//!     - Client
+//!         - NodeClient `new` `for_network` `balance` `asset_balances` `height`
+//!         - NodeClient `transaction_info` `unconfirmed` `broadcast` `broadcast_bytes`
+//!         - TransactionInfo `id` `type_id` `height` `json` `transaction`
+//!
+//!     - Tracker
+//!         - Tracker `new` `broadcast` `broadcast_bytes` `wait` -> `Result<Outcome, LunesError>`
+//!         - Outcome `Confirmed` | `Rejected` | `Expired` | `TimedOut`
+//!         - TrackerOptions `confirmations` `interval` `timeout`
+//!
+//!     - Transport
+//!         - Transport `send` `(&Request)` -> `Result<Response, NodeError>` `sleep`
+//!         - HttpTransport (feature `http`)
 //!
 //! - **Utils**
 //!     - Serialize
 //!         - serialize_string `String` -> `Vec<u8>`
 //!         - serialize_uinteger `u64` -> `Vec<u8>`
+//!         - serialize_array `Vec<u8>` -> `Result<Vec<u8>, LunesError>`
 //!
 //!      - Crypto
 //!         - to_blake2b32b `Vec<u8>` -> `Vec<u8>`
+//!         - try_to_blake2b32b `Vec<u8>` -> `Result<Vec<u8>, LunesError>`
 //!         - to_keccak256 `Vec<u8>` -> `Vec<u8>`
 //!         - to_sha256 `Vec<u8>` -> `Vec<u8>`
 //!
@@ -53,15 +178,42 @@
 //!         - to_vecu32 `Vec<u8>` -> `Vec<u32>`
 //!         - to_vecu8 `Vec<u32>` -> `Vec<u8>`
 //!
+//!      - Amount
+//!         - Amount `units` `decimals` `to_fixed` `Display`
+//!         - Amount `new` `lunes` `parse` `checked_add` `checked_sub` `checked_mul` -> `Result<Amount, LunesError>`
+//!         - Amount `to_bytes` -> `Vec<u8>`
+//!
 //!      - Base58
 //!         - vec_to_b58 `Vec<u8>` -> `String`
 //!         - b58_to_vec `String` -> `Vec<u8>`
+//!         - try_b58_to_vec `String` -> `Result<Vec<u8>, LunesError>`
+//!
+//! - **Error**
+//!     - LunesError, thrown like `Error` in JavaScript
+//!
+//! - **Cli** (feature `cli`)
+//!     - `lunes` binary, every command prints JSON
+//!         - `seed new` `account derive` `address validate` `sign` `verify`
+//!         - `base58 encode` `base58 decode` `tx build`
+//!         - Cli `run` -> `Result<serde_json::Value, LunesError>`
 //!
 
 /// Functions for wallets for Lunes Blockchain
 pub mod wallet;
 /// Utils functions
 pub mod utils;
+/// Build, serialize and sign transactions for Lunes Blockchain
+pub mod transactions;
+/// Mainnet, testnet and private networks of Lunes Blockchain
+pub mod network;
+/// Client of the REST API of the nodes of Lunes Blockchain
+#[cfg(feature = "node")]
+pub mod node;
+/// Errors of lunesrs, thrown like `Error` in JavaScript
+pub mod error;
+/// Arguments and commands of the `lunes` binary
+#[cfg(feature = "cli")]
+pub mod cli;
 
 #[cfg(feature = "wee_alloc")]
 #[global_allocator]
@@ -0,0 +1,222 @@
+use std::str::FromStr;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+
+/// Chain byte of the addresses and aliases of the mainnet, `'1'`
+pub const MAINNET_CHAIN_BYTE: u8 = b'1';
+/// Chain byte of the addresses and aliases of the testnet, `'0'`
+pub const TESTNET_CHAIN_BYTE: u8 = b'0';
+/// Public nodes of the mainnet
+pub const MAINNET_NODE_URLS: [&str; 1] = ["https://lunesnode.lunes.io"];
+/// Public nodes of the testnet
+pub const TESTNET_NODE_URLS: [&str; 1] = ["https://lunesnode-testnet.lunes.io"];
+
+/// Minimum fees of a network in the smallest unit, 1 LUNES = 100000000, see `transactions::fee`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeDefaults {
+    pub transfer: u64,
+    pub issue: u64,
+    pub reissue: u64,
+    pub burn: u64,
+    pub lease: u64,
+    pub lease_cancel: u64,
+    pub create_alias: u64,
+    /// Mass transfer fee is `mass_transfer + mass_transfer_per_transfer * transfers`
+    pub mass_transfer: u64,
+    pub mass_transfer_per_transfer: u64,
+    /// Data fee is `data_per_kb` for every started KB of the signed transaction
+    pub data_per_kb: u64,
+    /// Added when the sender is a smart account
+    pub smart_account: u64,
+    /// Added for every smart asset of the transaction
+    pub smart_asset: u64,
+}
+
+/// Minimum fees of the nodes of the mainnet
+pub const MAINNET_FEE_DEFAULTS: FeeDefaults = FeeDefaults {
+    transfer: 100000,
+    issue: 100000000,
+    reissue: 100000000,
+    burn: 100000,
+    lease: 100000,
+    lease_cancel: 100000,
+    create_alias: 100000,
+    mass_transfer: 100000,
+    mass_transfer_per_transfer: 50000,
+    data_per_kb: 100000,
+    smart_account: 400000,
+    smart_asset: 400000,
+};
+
+impl Default for FeeDefaults {
+    /// Fees of the mainnet
+    fn default() -> Self {
+        MAINNET_FEE_DEFAULTS
+    }
+}
+
+/**
+# Network
+## Chain of the addresses, aliases and transactions
+
+- The chain byte is stored in every address and alias: `'1'` in the mainnet, `'0'` in the testnet
+- `Custom` is a private network, its chain byte can be a digit or a letter like `b'D'`
+- The functions for JavaScript take the chain id of `chain_id`, a digit or the chain byte of a letter
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const devnet: number = wasm.chainIdOf("D")
+const account = wasm.Account.fromSeed("scrub guard swim ...", 0, devnet)
+
+wasm.chainIdOf("mainnet") === 1
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+
+let devnet = Network::Custom { byte: b'D' };
+
+assert_eq!(Network::Mainnet.chain_byte(), b'1');
+assert_eq!(Network::Testnet.chain_id(), 0);
+assert_eq!(devnet.chain_id(), b'D');
+assert_eq!(Network::from(1), Network::Mainnet);
+assert_eq!(Network::from(b'D'), devnet);
+assert_eq!("testnet".parse(), Ok(Network::Testnet));
+assert_eq!(devnet.to_string(), "D");
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Testnet,
+    /// Private network, build it with `from_chain_byte` so `b'1'` and `b'0'` are not custom
+    Custom {
+        byte: u8,
+    },
+}
+
+impl Network {
+    /// Network of a chain byte like stored in addresses and aliases
+    pub fn from_chain_byte(byte: u8) -> Network {
+        match byte {
+            MAINNET_CHAIN_BYTE => Network::Mainnet,
+            TESTNET_CHAIN_BYTE => Network::Testnet,
+            byte => Network::Custom { byte },
+        }
+    }
+
+    /// Network of a chain id, `0` to `9` are digit chains and any other id is the chain byte
+    pub fn from_chain_id(chain_id: u8) -> Network {
+        match chain_id {
+            0..=9 => Network::from_chain_byte(b'0' + chain_id),
+            byte => Network::from_chain_byte(byte),
+        }
+    }
+
+    /// Byte stored in the addresses and aliases of the network
+    pub fn chain_byte(&self) -> u8 {
+        match self {
+            Network::Mainnet => MAINNET_CHAIN_BYTE,
+            Network::Testnet => TESTNET_CHAIN_BYTE,
+            Network::Custom { byte } => *byte,
+        }
+    }
+
+    /// Chain id taken by the functions for JavaScript, the inverse of `from_chain_id`
+    pub fn chain_id(&self) -> u8 {
+        match self.chain_byte() {
+            byte @ b'0'..=b'9' => byte - b'0',
+            byte => byte,
+        }
+    }
+
+    /// Public nodes of the network, none for custom networks
+    pub fn node_urls(&self) -> &'static [&'static str] {
+        match self {
+            Network::Mainnet => &MAINNET_NODE_URLS,
+            Network::Testnet => &TESTNET_NODE_URLS,
+            Network::Custom { .. } => &[],
+        }
+    }
+
+    /**
+    # Fee Defaults
+    ## Minimum fees of the transactions in the network
+
+    - The mainnet and the testnet share the same schedule
+    - Custom networks take the fees of the mainnet, pass their own to `suggest_fee_with`
+    - The builders replace a zero fee with these, see `transactions::fee`
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::{FeeDefaults, Network, MAINNET_FEE_DEFAULTS};
+    use lunesrs::transactions::fee::{suggest_fee_with, SmartScripts};
+    use lunesrs::transactions::lease::LeaseCancelTransaction;
+
+    let devnet = Network::Custom { byte: b'E' };
+    let fees = FeeDefaults { lease_cancel: 1000, ..devnet.fee_defaults() };
+    let tx = LeaseCancelTransaction {
+        sender_public_key: vec![1; 32],
+        lease_id: vec![2; 32],
+        fee: 0,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(devnet.fee_defaults(), MAINNET_FEE_DEFAULTS);
+    assert_eq!(Network::Testnet.fee_defaults(), MAINNET_FEE_DEFAULTS);
+    assert_eq!(suggest_fee_with(&tx, &fees, SmartScripts::default()), 1000);
+    ```
+    */
+    pub fn fee_defaults(&self) -> FeeDefaults {
+        MAINNET_FEE_DEFAULTS
+    }
+}
+
+impl From<u8> for Network {
+    /// Network of a chain id, see `from_chain_id`
+    fn from(chain_id: u8) -> Self {
+        Network::from_chain_id(chain_id)
+    }
+}
+
+impl std::fmt::Display for Network {
+    /// `mainnet`, `testnet` or the chain byte of a custom network
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Network::Mainnet => write!(f, "mainnet"),
+            Network::Testnet => write!(f, "testnet"),
+            Network::Custom { byte } => write!(f, "{}", *byte as char),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = LunesError;
+
+    /// Parse `mainnet`, `testnet` or a chain byte like `D`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mainnet" => Ok(Network::Mainnet),
+            "testnet" => Ok(Network::Testnet),
+            _ if s.len() == 1 && s.as_bytes()[0].is_ascii_alphanumeric() => {
+                Ok(Network::from_chain_byte(s.as_bytes()[0]))
+            }
+            _ => Err(LunesError::InvalidNetwork(s.to_string())),
+        }
+    }
+}
+
+/// Chain id of `mainnet`, `testnet` or a chain byte like `D`, see `Network`
+#[wasm_bindgen(js_name = "chainIdOf")]
+pub fn chain_id_of(network: String) -> Result<u8, LunesError> {
+    network.parse::<Network>().map(|network| network.chain_id())
+}
@@ -0,0 +1,243 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::node::transport::{Method, Request, Response, Transport};
+use crate::node::NodeError;
+use crate::transactions::parser::{parse_transaction, SignedTransaction};
+use crate::wallet::address::Address;
+
+/// Balance of LUNES of an address in the smallest unit
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Balance {
+    pub address: Address,
+    pub confirmations: u64,
+    pub balance: u64,
+}
+
+/// Balance of a token of an address in the smallest unit of the token
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetBalance {
+    #[serde(with = "crate::transactions::json::base58")]
+    pub asset_id: Vec<u8>,
+    pub balance: u64,
+    #[serde(default)]
+    pub reissuable: bool,
+    #[serde(default)]
+    pub quantity: u64,
+}
+
+#[derive(Deserialize)]
+struct AssetBalances {
+    balances: Vec<AssetBalance>,
+}
+
+#[derive(Deserialize)]
+struct Height {
+    height: u64,
+}
+
+/// Transaction like the node shows it
+///
+/// - `height` is `None` while the transaction is unconfirmed
+/// - `json` keeps every field, `transaction` decodes it when lunesrs supports the type
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionInfo {
+    pub id: String,
+    pub type_id: u8,
+    pub height: Option<u64>,
+    pub json: Value,
+}
+
+impl TransactionInfo {
+    fn from_json(json: Value) -> Result<TransactionInfo, NodeError> {
+        #[derive(Deserialize)]
+        struct Fields {
+            id: String,
+            #[serde(rename = "type")]
+            type_id: u8,
+            height: Option<u64>,
+        }
+
+        let fields = Fields::deserialize(&json).map_err(invalid_response)?;
+        Ok(TransactionInfo {
+            id: fields.id,
+            type_id: fields.type_id,
+            height: fields.height,
+            json,
+        })
+    }
+
+    /// Typed transaction with its signature, fail for types lunesrs does not build
+    ///
+    /// - The network of the node gives the chain of an alias, see `SignedTransaction::from_json`
+    pub fn transaction(
+        &self,
+        network: impl Into<Network>,
+    ) -> Result<SignedTransaction, LunesError> {
+        SignedTransaction::from_json(self.json.clone(), network)
+            .map_err(|e| LunesError::Json(e.to_string()))
+    }
+}
+
+/**
+# Node Client
+## Typed calls to the REST API of a Lunes node
+
+- Every call goes through the `Transport`, build it with `HttpTransport` (feature `http`) or a stub
+- Error statuses of the node become `NodeError::Api` with the code and the message of the node
+- Available with the feature `node`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::transport::{Request, Response};
+use lunesrs::node::NodeError;
+
+let stub = |request: &Request| -> Result<Response, NodeError> {
+    assert_eq!(request.url, "https://lunesnode.lunes.io/addresses/balance/37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+    Ok(Response::ok(
+        r#"{"address": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj", "confirmations": 0, "balance": 100000000}"#,
+    ))
+};
+let client = NodeClient::for_network(Network::Mainnet, stub).unwrap();
+let address = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap();
+
+assert_eq!(client.balance(&address).unwrap().balance, 100000000);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct NodeClient<T: Transport> {
+    url: String,
+    transport: T,
+}
+
+impl<T: Transport> NodeClient<T> {
+    /// Client of the node at `url`, like `https://lunesnode.lunes.io`
+    pub fn new(url: impl Into<String>, transport: T) -> NodeClient<T> {
+        NodeClient {
+            url: url.into().trim_end_matches('/').to_string(),
+            transport,
+        }
+    }
+
+    /// Client of the first public node of the network, see `Network::node_urls`
+    pub fn for_network(
+        network: impl Into<Network>,
+        transport: T,
+    ) -> Result<NodeClient<T>, LunesError> {
+        let network = network.into();
+        match network.node_urls().first() {
+            Some(url) => Ok(NodeClient::new(*url, transport)),
+            None => Err(NodeError::NoNodeUrl(network).into()),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// `GET /addresses/balance/{address}`
+    pub fn balance(&self, address: &Address) -> Result<Balance, LunesError> {
+        self.get(&format!("/addresses/balance/{}", address))
+    }
+
+    /// `GET /assets/balance/{address}`, every token of the address
+    pub fn asset_balances(&self, address: &Address) -> Result<Vec<AssetBalance>, LunesError> {
+        self.get::<AssetBalances>(&format!("/assets/balance/{}", address))
+            .map(|balances| balances.balances)
+    }
+
+    /// `GET /transactions/info/{id}`, `None` if the transaction is not in the blockchain
+    pub fn transaction_info(&self, id: &str) -> Result<Option<TransactionInfo>, LunesError> {
+        let response = self.send(Method::Get, &format!("/transactions/info/{}", id), None)?;
+        if response.status == 404 {
+            return Ok(None);
+        }
+
+        Ok(Some(TransactionInfo::from_json(decode(response)?)?))
+    }
+
+    /// `GET /transactions/unconfirmed`, the pool of transactions waiting for a block
+    pub fn unconfirmed(&self) -> Result<Vec<TransactionInfo>, LunesError> {
+        self.get::<Vec<Value>>("/transactions/unconfirmed")?
+            .into_iter()
+            .map(|json| TransactionInfo::from_json(json).map_err(LunesError::from))
+            .collect()
+    }
+
+    /// `GET /blocks/height`, height of the last block
+    pub fn height(&self) -> Result<u64, LunesError> {
+        self.get::<Height>("/blocks/height")
+            .map(|height| height.height)
+    }
+
+    /// `POST /transactions/broadcast`, the node answers with the unconfirmed transaction
+    pub fn broadcast(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionInfo, LunesError> {
+        let body =
+            serde_json::to_string(transaction).map_err(|e| LunesError::Json(e.to_string()))?;
+        let response = self.send(Method::Post, "/transactions/broadcast", Some(body))?;
+
+        Ok(TransactionInfo::from_json(decode(response)?)?)
+    }
+
+    /// Parse the signed transaction like bytes and broadcast it, see `broadcast`
+    pub fn broadcast_bytes(&self, signed_bytes: Vec<u8>) -> Result<TransactionInfo, LunesError> {
+        self.broadcast(&parse_transaction(signed_bytes)?)
+    }
+
+    fn get<R: DeserializeOwned>(&self, path: &str) -> Result<R, LunesError> {
+        let json = decode(self.send(Method::Get, path, None)?)?;
+        serde_json::from_value(json).map_err(|e| invalid_response(e).into())
+    }
+
+    fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<Response, NodeError> {
+        self.transport.send(&Request {
+            method,
+            url: format!("{}{}", self.url, path),
+            body,
+        })
+    }
+}
+
+/// JSON of a successful response, or the error of the node
+fn decode(response: Response) -> Result<Value, NodeError> {
+    let json = serde_json::from_str::<Value>(&response.body);
+    if response.is_success() {
+        return json.map_err(invalid_response);
+    }
+
+    let json = json.unwrap_or(Value::Null);
+    let message = ["message", "details"]
+        .iter()
+        .find_map(|field| json.get(field).and_then(Value::as_str))
+        .map(str::to_string)
+        .unwrap_or(response.body);
+
+    Err(NodeError::Api {
+        status: response.status,
+        code: json.get("error").and_then(Value::as_i64),
+        message,
+    })
+}
+
+fn invalid_response(e: serde_json::Error) -> NodeError {
+    NodeError::InvalidResponse(e.to_string())
+}
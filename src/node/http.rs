@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::node::transport::{Method, Request, Response, Transport};
+use crate::node::NodeError;
+
+/// Time to connect and to read a response before the request fails
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/**
+# HTTP Transport
+## Send the requests of a `NodeClient` over HTTP
+
+- Blocking, built on ureq with rustls, not available in WebAssembly
+- Available with the feature `http`
+
+## In Rust 🤝
+
+```rust,no_run
+use lunesrs::network::Network;
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::http::HttpTransport;
+
+let client = NodeClient::for_network(Network::Testnet, HttpTransport::default()).unwrap();
+let height = client.height().unwrap();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    pub fn new(timeout: Duration) -> HttpTransport {
+        HttpTransport {
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+        }
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        HttpTransport::new(DEFAULT_TIMEOUT)
+    }
+}
+
+impl Transport for HttpTransport {
+    fn send(&self, request: &Request) -> Result<Response, NodeError> {
+        let result = match (request.method, &request.body) {
+            (Method::Get, _) => self.agent.get(&request.url).call(),
+            (Method::Post, body) => self
+                .agent
+                .post(&request.url)
+                .set("Content-Type", "application/json")
+                .send_string(body.as_deref().unwrap_or_default()),
+        };
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(e)) => return Err(NodeError::Transport(e.to_string())),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response
+                .into_string()
+                .map_err(|e| NodeError::Transport(e.to_string()))?,
+        })
+    }
+}
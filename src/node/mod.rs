@@ -0,0 +1,52 @@
+use crate::network::Network;
+
+/// Typed calls to the REST API of a node
+pub mod client;
+/// Blocking HTTP transport built on ureq
+#[cfg(feature = "http")]
+pub mod http;
+/// Broadcast a transaction and wait for its confirmations
+pub mod tracker;
+/// Requests and responses exchanged with a node, and the trait that sends them
+pub mod transport;
+
+/// Reasons a call to a node can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeError {
+    /// Request could not be sent or the response could not be read
+    Transport(String),
+    /// Node answered with an error status, `code` and `message` come from its JSON when present
+    Api {
+        status: u16,
+        code: Option<i64>,
+        message: String,
+    },
+    /// Response is not the JSON expected for the call
+    InvalidResponse(String),
+    /// Network has no public node, pass the URL of a node
+    NoNodeUrl(Network),
+}
+
+impl std::fmt::Display for NodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NodeError::Transport(e) => write!(f, "request to the node failed: {}", e),
+            NodeError::Api {
+                status,
+                code: Some(code),
+                message,
+            } => write!(f, "node error {} ({}): {}", code, status, message),
+            NodeError::Api {
+                status,
+                code: None,
+                message,
+            } => write!(f, "node error ({}): {}", status, message),
+            NodeError::InvalidResponse(e) => write!(f, "invalid response of the node: {}", e),
+            NodeError::NoNodeUrl(network) => {
+                write!(f, "network {} has no public node", network)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NodeError {}
@@ -0,0 +1,198 @@
+use std::time::Duration;
+
+use crate::error::LunesError;
+use crate::node::client::NodeClient;
+use crate::node::transport::Transport;
+use crate::node::NodeError;
+use crate::transactions::parser::{parse_transaction, SignedTransaction};
+
+/// A transaction in the last block has 1 confirmation
+pub const DEFAULT_CONFIRMATIONS: u64 = 1;
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// How long a `Tracker` waits and how often it polls the node
+///
+/// - `timeout` counts the `interval`s slept between polls, the time of the requests is not counted
+/// - A zero `interval` polls once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackerOptions {
+    pub confirmations: u64,
+    pub interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for TrackerOptions {
+    fn default() -> Self {
+        TrackerOptions {
+            confirmations: DEFAULT_CONFIRMATIONS,
+            interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_WAIT_TIMEOUT,
+        }
+    }
+}
+
+/// End of the wait for a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Transaction is in the block at `height` with at least the expected confirmations
+    Confirmed {
+        id: String,
+        height: u64,
+        confirmations: u64,
+    },
+    /// Node refused the broadcast, the transaction will never be in a block
+    Rejected {
+        id: String,
+        code: Option<i64>,
+        message: String,
+    },
+    /// Transaction is neither in the blockchain nor in the unconfirmed pool anymore
+    Expired { id: String },
+    /// Timeout elapsed, `height` is the block of the transaction if it was already in one
+    TimedOut { id: String, height: Option<u64> },
+}
+
+impl Outcome {
+    /// Transaction id encoded in base58
+    pub fn id(&self) -> &str {
+        match self {
+            Outcome::Confirmed { id, .. }
+            | Outcome::Rejected { id, .. }
+            | Outcome::Expired { id }
+            | Outcome::TimedOut { id, .. } => id,
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Outcome::Confirmed { .. })
+    }
+}
+
+/**
+# Tracker
+## Broadcast a transaction and wait until it has enough confirmations
+
+- The id is computed from the body bytes with `to_blake2b32b`, not taken from the node
+- Every poll looks for the transaction in the unconfirmed pool and then in the blockchain
+- A broadcast refused with a `4xx` status is `Outcome::Rejected`
+- Other errors of the node, like `5xx` while broadcasting or polling, are returned
+- Available with the feature `node`
+
+## In Rust 🤝
+
+```rust
+use std::time::Duration;
+
+use lunesrs::network::Network;
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::tracker::{Outcome, Tracker, TrackerOptions};
+use lunesrs::node::transport::{Request, Response};
+use lunesrs::node::NodeError;
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let prvk = to_private_key(vec![1; 32]);
+let tx = LeaseTransaction {
+    sender_public_key: to_public_key(prvk.clone()),
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    amount: 100000000,
+    fee: 100000,
+    timestamp: 1528077600000,
+};
+let id = tx.id().unwrap();
+let node = |request: &Request| -> Result<Response, NodeError> {
+    let path = request.url.trim_start_matches("http://localhost:5555");
+    Ok(Response::ok(match path {
+        "/transactions/broadcast" => format!(r#"{{"type": 8, "id": "{}"}}"#, id),
+        "/transactions/unconfirmed" => "[]".to_string(),
+        "/blocks/height" => r#"{"height": 1202}"#.to_string(),
+        _ => format!(r#"{{"type": 8, "id": "{}", "height": 1200}}"#, id),
+    }))
+};
+let client = NodeClient::new("http://localhost:5555", node);
+let options = TrackerOptions {
+    confirmations: 3,
+    interval: Duration::ZERO,
+    ..TrackerOptions::default()
+};
+let outcome = Tracker::new(&client, options).broadcast_bytes(tx.sign(prvk, Network::Mainnet).unwrap()).unwrap();
+
+assert_eq!(outcome, Outcome::Confirmed { id: tx.id().unwrap(), height: 1200, confirmations: 3 });
+```
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Tracker<'a, T: Transport> {
+    client: &'a NodeClient<T>,
+    options: TrackerOptions,
+}
+
+impl<'a, T: Transport> Tracker<'a, T> {
+    pub fn new(client: &'a NodeClient<T>, options: TrackerOptions) -> Tracker<'a, T> {
+        Tracker { client, options }
+    }
+
+    pub fn options(&self) -> TrackerOptions {
+        self.options
+    }
+
+    /// Broadcast the transaction and wait for it, see `wait`
+    pub fn broadcast(&self, transaction: &SignedTransaction) -> Result<Outcome, LunesError> {
+        let id = transaction.id()?;
+
+        match self.client.broadcast(transaction) {
+            Ok(info) if info.id != id => Err(NodeError::InvalidResponse(format!(
+                "node answered the broadcast of {} with the transaction {}",
+                id, info.id
+            ))
+            .into()),
+            Ok(_) => self.wait(&id),
+            Err(LunesError::Node(NodeError::Api {
+                status: 400..=499,
+                code,
+                message,
+            })) => Ok(Outcome::Rejected { id, code, message }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parse the signed transaction like bytes, broadcast it and wait for it
+    pub fn broadcast_bytes(&self, signed_bytes: Vec<u8>) -> Result<Outcome, LunesError> {
+        self.broadcast(&parse_transaction(signed_bytes)?)
+    }
+
+    /// Poll the node every `interval` until the transaction has the confirmations or `timeout`
+    pub fn wait(&self, id: &str) -> Result<Outcome, LunesError> {
+        let id = id.to_string();
+        let mut waited = Duration::ZERO;
+
+        loop {
+            let in_pool = self.client.unconfirmed()?.iter().any(|info| info.id == id);
+            let height = match in_pool {
+                true => None,
+                false => match self.client.transaction_info(&id)? {
+                    Some(info) => info.height,
+                    None => return Ok(Outcome::Expired { id }),
+                },
+            };
+
+            if let Some(height) = height {
+                let confirmations = (self.client.height()? + 1).saturating_sub(height);
+                if confirmations >= self.options.confirmations {
+                    return Ok(Outcome::Confirmed {
+                        id,
+                        height,
+                        confirmations,
+                    });
+                }
+            }
+
+            if waited >= self.options.timeout || self.options.interval.is_zero() {
+                return Ok(Outcome::TimedOut { id, height });
+            }
+            self.client.transport().sleep(self.options.interval);
+            waited += self.options.interval;
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use crate::node::NodeError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+/// Request to a node, `body` is the JSON of `POST` requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub body: Option<String>,
+}
+
+/// Status and body of the answer of a node, error statuses are not errors of the transport
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    /// Response with the status `200`
+    pub fn ok(body: impl Into<String>) -> Response {
+        Response {
+            status: 200,
+            body: body.into(),
+        }
+    }
+
+    /// `true` for the statuses `2xx`
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/**
+# Transport
+## Send the requests of a `NodeClient`
+
+- Implement it over any HTTP library, a local mock server or an in-memory stub
+- Return `NodeError::Transport` only when there is no response, error statuses are a `Response`
+- Closures `Fn(&Request) -> Result<Response, NodeError>` are transports
+- `sleep` waits between the polls of a `Tracker`, a fake node can override it to move its clock
+
+## In Rust 🤝
+
+```rust
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::transport::{Request, Response};
+use lunesrs::node::NodeError;
+
+let stub = |request: &Request| -> Result<Response, NodeError> {
+    match request.url.as_str() {
+        "http://localhost:5555/blocks/height" => Ok(Response::ok(r#"{"height": 42}"#)),
+        url => Err(NodeError::Transport(format!("no route to {}", url))),
+    }
+};
+let client = NodeClient::new("http://localhost:5555", stub);
+
+assert_eq!(client.height(), Ok(42));
+```
+*/
+pub trait Transport {
+    fn send(&self, request: &Request) -> Result<Response, NodeError>;
+
+    /// Block the thread for `duration`
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+impl<F> Transport for F
+where
+    F: Fn(&Request) -> Result<Response, NodeError>,
+{
+    fn send(&self, request: &Request) -> Result<Response, NodeError> {
+        self(request)
+    }
+}
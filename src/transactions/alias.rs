@@ -0,0 +1,292 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::transactions::fee::{suggest_fee_with, SmartScripts};
+use crate::transactions::reader::Reader;
+use crate::transactions::{
+    serialize_field, Transaction, TransactionError, ALIAS_ALPHABET, ALIAS_VERSION,
+    CREATE_ALIAS_TYPE, MAX_ALIAS_LENGTH, MIN_ALIAS_LENGTH,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// Human-readable name of an address in a chain
+///
+/// - `chain_id` is the same chain id given to `to_address`, see `Network::chain_id`
+/// - The JSON of the node only has `alias`, `chainId` is read if present but never written
+/// - Read the JSON with `SignedTransaction::from_json` to take the chain from the network
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alias {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "chainId",
+            default = "crate::transactions::json::mainnet",
+            skip_serializing
+        )
+    )]
+    pub chain_id: u8,
+    #[cfg_attr(feature = "serde", serde(rename = "alias"))]
+    pub name: String,
+}
+
+impl Alias {
+    /// Alias of a name in a network
+    pub fn new(network: impl Into<Network>, name: impl Into<String>) -> Alias {
+        Alias {
+            chain_id: network.into().chain_id(),
+            name: name.into(),
+        }
+    }
+
+    /// Network of the chain id
+    pub fn network(&self) -> Network {
+        Network::from_chain_id(self.chain_id)
+    }
+
+    /**
+    # Validate
+    ## Check the length and the characters of the alias
+
+    - Between 4 and 30 characters of `-.0123456789@_abcdefghijklmnopqrstuvwxyz`
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::alias::Alias;
+
+    let mainnet = 1;
+
+    assert_eq!(true, Alias { chain_id: mainnet, name: "lunes".to_string() }.validate().is_ok());
+    assert_eq!(true, Alias { chain_id: mainnet, name: "Lunes".to_string() }.validate().is_err());
+    assert_eq!(true, Alias { chain_id: mainnet, name: "lun".to_string() }.validate().is_err());
+    ```
+    */
+    pub fn validate(&self) -> Result<(), TransactionError> {
+        if (MIN_ALIAS_LENGTH..=MAX_ALIAS_LENGTH).contains(&self.name.len())
+            && self.name.chars().all(|c| ALIAS_ALPHABET.contains(c))
+        {
+            Ok(())
+        } else {
+            Err(TransactionError::InvalidAlias(self.name.clone()))
+        }
+    }
+
+    /**
+    # To Bytes
+    ## Serialize the alias as `version | chain | name length | name`
+
+    - The chain byte is built like in `to_address`
+    - Fail with `ArrayTooLong` if the name has more than 65535 bytes
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::alias::Alias;
+
+    let alias = Alias { chain_id: 1, name: "lunes".to_string() };
+
+    assert_eq!(alias.to_bytes(), Ok(vec![2, 49, 0, 5, 108, 117, 110, 101, 115]));
+    ```
+    */
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![ALIAS_VERSION, self.network().chain_byte()],
+            serialize_field(self.name.as_bytes().to_vec())?,
+        ]
+        .concat())
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        let offset = reader.offset();
+        reader.expect_version(ALIAS_VERSION)?;
+        let chain = reader.u8()?;
+        let name = reader.string()?;
+
+        if chain.is_ascii_alphanumeric() {
+            Ok(Alias::new(Network::from_chain_byte(chain), name))
+        } else {
+            Err(TransactionError::InvalidRecipient(format!(
+                "alias at offset {}",
+                offset
+            )))
+        }
+    }
+}
+
+impl std::fmt::Display for Alias {
+    /// Format as `alias:<chain byte>:<name>`, like the node does
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let chain = self.network().chain_byte() as char;
+        write!(f, "alias:{}:{}", chain, self.name)
+    }
+}
+
+/// Creation of `alias` pointing to the address of the sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CreateAliasTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub alias: Alias,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl CreateAliasTransaction {
+    /**
+    # Sign
+    ## Validate and sign the alias creation with your private key
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::alias::{Alias, CreateAliasTransaction};
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = CreateAliasTransaction {
+        sender_public_key: pubk.clone(),
+        alias: Alias { chain_id: 1, name: "lunes".to_string() },
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk).unwrap();
+    let body = tx.body_bytes().unwrap();
+
+    assert_eq!(true, validate_signature(pubk, body.clone(), signed[body.len()..].to_vec()));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        self.alias.validate()?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(CREATE_ALIAS_TYPE)?;
+        let sender_public_key = reader.public_key()?;
+        let alias_bytes = reader.array()?;
+        let mut alias_reader = Reader::new(&alias_bytes);
+        let alias = Alias::read(&mut alias_reader)?;
+        alias_reader.finish()?;
+
+        Ok(CreateAliasTransaction {
+            sender_public_key,
+            alias,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+impl Transaction for CreateAliasTransaction {
+    /// Serialize the creation as `type | sender public key | alias | fee | timestamp`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![CREATE_ALIAS_TYPE],
+            self.sender_public_key.clone(),
+            serialize_field(self.alias.to_bytes()?)?,
+            serialize_integer(self.fee),
+            serialize_integer(self.timestamp),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | signature`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, signature].concat())
+    }
+}
+
+/**
+# Sign Create Alias
+## Build, validate and sign an alias creation transaction
+
+- Throw if the alias is invalid
+- The alias is created in the network, like `alias:1:lunes` in the mainnet
+- A `fee` of `0` is replaced by the suggested fee of the network, see `suggest_fee_with`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::transactions::alias::sign_create_alias;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let private_key = to_private_key(vec![1; 32]);
+let public_key = to_public_key(private_key.clone());
+
+let signed = sign_create_alias(private_key, Network::Mainnet, public_key, "lunes".to_string(), 0, 1528077600000);
+
+assert_eq!(true, signed.is_ok());
+```
+*/
+pub fn sign_create_alias(
+    private_key: Vec<u8>,
+    network: impl Into<Network>,
+    sender_public_key: Vec<u8>,
+    alias: String,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let network: Network = network.into();
+    let mut tx = CreateAliasTransaction {
+        sender_public_key,
+        alias: Alias::new(network, alias),
+        fee,
+        timestamp,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee_with(&tx, &network.fee_defaults(), SmartScripts::default());
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
+
+/**
+# Sign Create Alias
+## `sign_create_alias` for JavaScript, with the chain id of the network
+
+- `chainId` is the chain id of `Network::chain_id`, take it from `chainIdOf("mainnet")`
+- Throw if the alias is invalid
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const mainnet: number = wasm.chainIdOf("mainnet")
+
+const signed: Uint8Array = wasm.signCreateAlias(
+    privateKey, mainnet, publicKey, "lunes", 100000n, BigInt(Date.now())
+)
+```
+*/
+#[wasm_bindgen(js_name = "signCreateAlias")]
+pub fn sign_create_alias_js(
+    private_key: Vec<u8>,
+    chain_id: u8,
+    sender_public_key: Vec<u8>,
+    alias: String,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    sign_create_alias(
+        private_key,
+        Network::from(chain_id),
+        sender_public_key,
+        alias,
+        fee,
+        timestamp,
+    )
+}
@@ -0,0 +1,146 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+
+use crate::transactions::fee::suggest_fee;
+use crate::transactions::reader::Reader;
+use crate::transactions::validation::first_error;
+use crate::transactions::{Transaction, TransactionError, ASSET_ID_LENGTH, BURN_TYPE};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// Burn of `amount` units of a token held by the sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BurnTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub asset_id: Vec<u8>,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl BurnTransaction {
+    /// Asset id that the node refuses, see `Validate`
+    pub(crate) fn errors(&self) -> Vec<TransactionError> {
+        let asset_id = self.asset_id.len();
+
+        if asset_id == ASSET_ID_LENGTH as usize {
+            vec![]
+        } else {
+            vec![TransactionError::InvalidAssetId(asset_id)]
+        }
+    }
+
+    /**
+    # Sign
+    ## Validate and sign the burn with your private key
+
+    - Fail with `InvalidAssetId` if the asset id does not have 32 bytes
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::burn::BurnTransaction;
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = BurnTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk).unwrap();
+    let body = tx.body_bytes().unwrap();
+
+    assert_eq!(true, validate_signature(pubk, body.clone(), signed[body.len()..].to_vec()));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        first_error(self.errors())?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(BURN_TYPE)?;
+
+        Ok(BurnTransaction {
+            sender_public_key: reader.public_key()?,
+            asset_id: reader.bytes(ASSET_ID_LENGTH as usize)?,
+            amount: reader.u64()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+impl Transaction for BurnTransaction {
+    /// Serialize the burn as `type | sender public key | asset id | amount | fee | timestamp`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![BURN_TYPE],
+            self.sender_public_key.clone(),
+            self.asset_id.clone(),
+            serialize_integer(self.amount),
+            serialize_integer(self.fee),
+            serialize_integer(self.timestamp),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | signature`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, signature].concat())
+    }
+}
+
+/**
+# Sign Burn
+## Build, validate and sign a burn transaction
+
+- Throw if the asset id does not have 32 bytes
+- A `fee` of `0` is replaced by the suggested fee, see `suggest_fee`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const assetId: Uint8Array = wasm.base58ToArray("2bkjzFqTMM3cQpbgGYKE8r7J73SrXFH8YfxFBRBterLt")
+
+const signed: Uint8Array = wasm.signBurn(privateKey, publicKey, assetId, 1000n, 100000n, BigInt(Date.now()))
+```
+*/
+#[wasm_bindgen(js_name = "signBurn")]
+pub fn sign_burn(
+    private_key: Vec<u8>,
+    sender_public_key: Vec<u8>,
+    asset_id: Vec<u8>,
+    amount: u64,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let mut tx = BurnTransaction {
+        sender_public_key,
+        asset_id,
+        amount,
+        fee,
+        timestamp,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee(&tx);
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
@@ -0,0 +1,317 @@
+#[cfg(feature = "serde")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[cfg(feature = "serde")]
+use crate::error::LunesError;
+
+#[cfg(feature = "serde")]
+use crate::transactions::fee::suggest_fee;
+use crate::transactions::reader::Reader;
+use crate::transactions::validation::first_error;
+use crate::transactions::{
+    serialize_count, serialize_field, serialize_proofs, Transaction, TransactionError, DATA_TYPE,
+    DATA_VERSION, MAX_DATA_ENTRIES, MAX_DATA_KEY_LENGTH, MAX_DATA_TRANSACTION_BYTES,
+    MAX_DATA_VALUE_LENGTH, PUBLIC_KEY_LENGTH,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+pub const INTEGER_TYPE: u8 = 0;
+pub const BOOLEAN_TYPE: u8 = 1;
+pub const BINARY_TYPE: u8 = 2;
+pub const STRING_TYPE: u8 = 3;
+
+/// Typed value of a data entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "lowercase")
+)]
+pub enum DataValue {
+    Integer(i64),
+    Boolean(bool),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::transactions::json::base64_prefixed")
+    )]
+    Binary(Vec<u8>),
+    String(String),
+}
+
+impl DataValue {
+    /// Type byte of the value, `0` integer, `1` boolean, `2` binary, `3` string
+    pub fn type_tag(&self) -> u8 {
+        match self {
+            DataValue::Integer(_) => INTEGER_TYPE,
+            DataValue::Boolean(_) => BOOLEAN_TYPE,
+            DataValue::Binary(_) => BINARY_TYPE,
+            DataValue::String(_) => STRING_TYPE,
+        }
+    }
+
+    /// Serialize the value as `type | value`, binary and string values are prefixed by their length
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        let value = match self {
+            DataValue::Integer(x) => serialize_integer(*x as u64),
+            DataValue::Boolean(x) => vec![*x as u8],
+            DataValue::Binary(x) => serialize_field(x.clone())?,
+            DataValue::String(x) => serialize_field(x.as_bytes().to_vec())?,
+        };
+
+        Ok([vec![self.type_tag()], value].concat())
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            DataValue::Integer(_) | DataValue::Boolean(_) => 0,
+            DataValue::Binary(x) => x.len(),
+            DataValue::String(x) => x.len(),
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        match reader.u8()? {
+            INTEGER_TYPE => Ok(DataValue::Integer(reader.u64()? as i64)),
+            BOOLEAN_TYPE => Ok(DataValue::Boolean(reader.bool()?)),
+            BINARY_TYPE => Ok(DataValue::Binary(reader.array()?)),
+            STRING_TYPE => Ok(DataValue::String(reader.string()?)),
+            byte => Err(TransactionError::InvalidDataType(byte)),
+        }
+    }
+}
+
+/// Key and typed value stored in the account of the sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DataEntry {
+    pub key: String,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub value: DataValue,
+}
+
+impl DataEntry {
+    /// Serialize the entry as `key | type | value`
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            serialize_field(self.key.as_bytes().to_vec())?,
+            self.value.to_bytes()?,
+        ]
+        .concat())
+    }
+}
+
+/// Storage of `entries` in the account of the sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DataTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(rename = "data"))]
+    pub entries: Vec<DataEntry>,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl DataTransaction {
+    /// Entries and size that the node refuses, see `Validate`
+    ///
+    /// - At most 100 entries with unique keys of 1 to 100 bytes
+    /// - Binary and string values of at most 32767 bytes
+    /// - At most 150 KB in total
+    pub(crate) fn errors(&self) -> Vec<TransactionError> {
+        if self.entries.len() > MAX_DATA_ENTRIES {
+            return vec![TransactionError::TooManyEntries(self.entries.len())];
+        }
+
+        let mut errors = vec![];
+        for (i, entry) in self.entries.iter().enumerate() {
+            if entry.key.is_empty() || entry.key.len() > MAX_DATA_KEY_LENGTH {
+                errors.push(TransactionError::InvalidKeyLength(entry.key.clone()));
+            }
+            if entry.value.len() > MAX_DATA_VALUE_LENGTH {
+                errors.push(TransactionError::ValueTooLong(entry.key.clone()));
+            }
+            if self.entries[..i].iter().any(|x| x.key == entry.key) {
+                errors.push(TransactionError::DuplicateKey(entry.key.clone()));
+            }
+        }
+
+        // Entries too long for their length prefix can not be serialized
+        if errors.is_empty() {
+            match self.body_bytes() {
+                Ok(body) if body.len() > MAX_DATA_TRANSACTION_BYTES => {
+                    errors.push(TransactionError::TransactionTooLarge(body.len()))
+                }
+                Ok(_) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+
+        errors
+    }
+
+    /**
+    # From Body Bytes
+    ## Parse the body bytes back into a data transaction
+
+    - Fail on truncated input, unknown types or trailing bytes
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+    use lunesrs::transactions::Transaction;
+
+    let tx = DataTransaction {
+        sender_public_key: vec![1; 32],
+        entries: vec![
+            DataEntry { key: "name".to_string(), value: DataValue::String("Lunes".to_string()) },
+            DataEntry { key: "active".to_string(), value: DataValue::Boolean(true) },
+        ],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(DataTransaction::from_body_bytes(tx.body_bytes().unwrap()), Ok(tx));
+    ```
+    */
+    pub fn from_body_bytes(bytes: Vec<u8>) -> Result<Self, TransactionError> {
+        let mut reader = Reader::new(&bytes);
+        let tx = Self::read(&mut reader)?;
+        reader.finish()?;
+
+        Ok(tx)
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(DATA_TYPE)?;
+        reader.expect_version(DATA_VERSION)?;
+        let sender_public_key = reader.bytes(PUBLIC_KEY_LENGTH as usize)?;
+        let entries = (0..reader.u16()?)
+            .map(|_| {
+                Ok(DataEntry {
+                    key: reader.string()?,
+                    value: DataValue::read(reader)?,
+                })
+            })
+            .collect::<Result<Vec<DataEntry>, TransactionError>>()?;
+        let timestamp = reader.u64()?;
+        let fee = reader.u64()?;
+
+        Ok(DataTransaction {
+            sender_public_key,
+            entries,
+            fee,
+            timestamp,
+        })
+    }
+
+    /**
+    # Sign
+    ## Validate and sign the data transaction with your private key
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = DataTransaction {
+        sender_public_key: pubk.clone(),
+        entries: vec![DataEntry { key: "score".to_string(), value: DataValue::Integer(10) }],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk).unwrap();
+    let signature = signed[signed.len() - 64..].to_vec();
+
+    assert_eq!(true, validate_signature(pubk, tx.body_bytes().unwrap(), signature));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        first_error(self.errors())?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+}
+
+impl Transaction for DataTransaction {
+    /// Serialize the data transaction as
+    /// `type | version | sender public key | count | entries | timestamp | fee`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        let entries = self
+            .entries
+            .iter()
+            .map(DataEntry::to_bytes)
+            .collect::<Result<Vec<Vec<u8>>, TransactionError>>()?;
+
+        Ok([
+            vec![DATA_TYPE, DATA_VERSION],
+            self.sender_public_key.clone(),
+            serialize_count(self.entries.len())?,
+            entries.concat(),
+            serialize_integer(self.timestamp),
+            serialize_integer(self.fee),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | proofs`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, serialize_proofs(signature)?].concat())
+    }
+}
+
+/**
+# Sign Data
+## Build, validate and sign a data transaction
+
+- `entries` is the JSON array of entries of the node, like `[{"key": "score", "type": "integer", "value": 10}]`
+- Binary values are strings like `base64:AQID`
+- Throw if the JSON is malformed or an entry is invalid, see `Validate`
+- A `fee` of `0` is replaced by the suggested fee, see `suggest_fee`
+- Available with the feature `serde`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const entries = JSON.stringify([
+    { key: "score", type: "integer", value: 10 },
+    { key: "name", type: "string", value: "Lunes" },
+])
+
+const signed: Uint8Array = wasm.signData(privateKey, publicKey, entries, 0n, BigInt(Date.now()))
+```
+*/
+#[cfg(feature = "serde")]
+#[wasm_bindgen(js_name = "signData")]
+pub fn sign_data(
+    private_key: Vec<u8>,
+    sender_public_key: Vec<u8>,
+    entries: String,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let mut tx = DataTransaction {
+        sender_public_key,
+        entries: serde_json::from_str(&entries).map_err(|e| LunesError::Json(e.to_string()))?,
+        fee,
+        timestamp,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee(&tx);
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
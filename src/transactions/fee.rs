@@ -0,0 +1,188 @@
+use crate::network::{FeeDefaults, Network};
+use crate::transactions::alias::CreateAliasTransaction;
+use crate::transactions::burn::BurnTransaction;
+use crate::transactions::data::DataTransaction;
+use crate::transactions::issue::IssueTransaction;
+use crate::transactions::lease::{LeaseCancelTransaction, LeaseTransaction};
+use crate::transactions::mass_transfer::MassTransferTransaction;
+use crate::transactions::parser::TransactionKind;
+use crate::transactions::reissue::ReissueTransaction;
+use crate::transactions::transfer::TransferTransaction;
+use crate::transactions::{Transaction, SIGNATURE_LENGTH};
+
+/// Bytes of a started KB of a data transaction
+pub const DATA_FEE_UNIT: usize = 1024;
+
+/// Scripts the node runs for a transaction, each one adds its extra fee
+///
+/// - `account` is `true` when the sender is a smart account
+/// - `assets` counts the smart assets of the transaction, like the transferred asset and the fee asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SmartScripts {
+    pub account: bool,
+    pub assets: u64,
+}
+
+impl SmartScripts {
+    /// Fee added to the minimum fee of the transaction
+    pub fn extra_fee(&self, fees: &FeeDefaults) -> u64 {
+        (fees.smart_account * self.account as u64)
+            .saturating_add(fees.smart_asset.saturating_mul(self.assets))
+    }
+}
+
+/// Transactions that know their minimum fee
+pub trait Fee {
+    /// Minimum fee of the transaction in a fee schedule, without smart scripts
+    fn required_fee(&self, fees: &FeeDefaults) -> u64;
+}
+
+impl Fee for TransferTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.transfer
+    }
+}
+
+impl Fee for IssueTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.issue
+    }
+}
+
+impl Fee for ReissueTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.reissue
+    }
+}
+
+impl Fee for BurnTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.burn
+    }
+}
+
+impl Fee for LeaseTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.lease
+    }
+}
+
+impl Fee for LeaseCancelTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.lease_cancel
+    }
+}
+
+impl Fee for CreateAliasTransaction {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.create_alias
+    }
+}
+
+impl Fee for MassTransferTransaction {
+    /// `mass_transfer + mass_transfer_per_transfer * transfers`
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        fees.mass_transfer.saturating_add(
+            fees.mass_transfer_per_transfer
+                .saturating_mul(self.transfers.len() as u64),
+        )
+    }
+}
+
+impl Fee for DataTransaction {
+    /// `data_per_kb` for every started KB of the signed transaction
+    ///
+    /// - A transaction that can not be serialized gets the saturated fee
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        let size = self
+            .signed_bytes(vec![0; SIGNATURE_LENGTH as usize])
+            .map_or(usize::MAX, |signed| signed.len());
+
+        fees.data_per_kb
+            .saturating_mul(size.div_ceil(DATA_FEE_UNIT) as u64)
+    }
+}
+
+impl Fee for TransactionKind {
+    fn required_fee(&self, fees: &FeeDefaults) -> u64 {
+        match self {
+            TransactionKind::Issue(tx) => tx.required_fee(fees),
+            TransactionKind::Transfer(tx) => tx.required_fee(fees),
+            TransactionKind::Reissue(tx) => tx.required_fee(fees),
+            TransactionKind::Burn(tx) => tx.required_fee(fees),
+            TransactionKind::Lease(tx) => tx.required_fee(fees),
+            TransactionKind::LeaseCancel(tx) => tx.required_fee(fees),
+            TransactionKind::CreateAlias(tx) => tx.required_fee(fees),
+            TransactionKind::MassTransfer(tx) => tx.required_fee(fees),
+            TransactionKind::Data(tx) => tx.required_fee(fees),
+        }
+    }
+}
+
+/**
+# Suggest Fee
+## Minimum fee of a transaction in the mainnet
+
+- The fee of the transaction itself is ignored, only its type, transfers and size count
+- The `sign_*` builders without a chain id use it when they receive a fee of `0`
+- For another network or smart scripts use `suggest_fee_with`, like the builders with a chain id
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::fee::suggest_fee;
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+
+let tx = MassTransferTransaction {
+    sender_public_key: vec![1; 32],
+    asset_id: None,
+    transfers: vec![Transfer {
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+    }],
+    fee: 0,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+
+assert_eq!(suggest_fee(&tx), 150000);
+```
+*/
+pub fn suggest_fee(tx: &impl Fee) -> u64 {
+    tx.required_fee(&Network::Mainnet.fee_defaults())
+}
+
+/**
+# Suggest Fee With
+## Minimum fee of a transaction in a network, with its smart scripts
+
+- Build the `FeeDefaults` of a private network by hand or take them from `Network::fee_defaults`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::{FeeDefaults, Network};
+use lunesrs::transactions::fee::{suggest_fee_with, SmartScripts};
+use lunesrs::transactions::transfer::TransferTransaction;
+
+let tx = TransferTransaction {
+    sender_public_key: vec![1; 32],
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    asset_id: Some(vec![2; 32]),
+    fee_asset: None,
+    amount: 100000000,
+    fee: 0,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+let smart = SmartScripts { account: true, assets: 1 };
+let devnet = FeeDefaults { transfer: 1000, ..Network::Testnet.fee_defaults() };
+
+assert_eq!(suggest_fee_with(&tx, &Network::Testnet.fee_defaults(), smart), 900000);
+assert_eq!(suggest_fee_with(&tx, &devnet, SmartScripts::default()), 1000);
+```
+*/
+pub fn suggest_fee_with(tx: &impl Fee, fees: &FeeDefaults, scripts: SmartScripts) -> u64 {
+    tx.required_fee(fees)
+        .saturating_add(scripts.extra_fee(fees))
+}
@@ -0,0 +1,179 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+
+use crate::transactions::fee::suggest_fee;
+use crate::transactions::reader::Reader;
+use crate::transactions::validation::first_error;
+use crate::transactions::{
+    serialize_field, Transaction, TransactionError, ISSUE_TYPE, MAX_ASSET_DECIMALS,
+    MAX_ASSET_DESCRIPTION_LENGTH, MAX_ASSET_NAME_LENGTH, MIN_ASSET_NAME_LENGTH,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// Issue of `quantity` units of a new token named `name`
+///
+/// - `quantity` is in the smallest unit, e.g. `100` with `2` decimals is `1.00`
+/// - If `reissuable` is `false` the supply can never grow
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct IssueTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    pub name: String,
+    pub description: String,
+    pub quantity: u64,
+    pub decimals: u8,
+    pub reissuable: bool,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl IssueTransaction {
+    /// Name, description and decimals that the node refuses, see `Validate`
+    pub(crate) fn errors(&self) -> Vec<TransactionError> {
+        let name = self.name.len();
+        let description = self.description.len();
+        let mut errors = vec![];
+
+        if !(MIN_ASSET_NAME_LENGTH..=MAX_ASSET_NAME_LENGTH).contains(&name) {
+            errors.push(TransactionError::InvalidNameLength(name));
+        }
+        if description > MAX_ASSET_DESCRIPTION_LENGTH {
+            errors.push(TransactionError::InvalidDescriptionLength(description));
+        }
+        if self.decimals > MAX_ASSET_DECIMALS {
+            errors.push(TransactionError::InvalidDecimals(self.decimals));
+        }
+
+        errors
+    }
+
+    /**
+    # Sign
+    ## Validate and sign the issue with your private key
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::issue::IssueTransaction;
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = IssueTransaction {
+        sender_public_key: pubk.clone(),
+        name: "Lunes Token".to_string(),
+        description: "My first token".to_string(),
+        quantity: 1000,
+        decimals: 2,
+        reissuable: true,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk).unwrap();
+
+    assert_eq!(true, validate_signature(pubk, tx.body_bytes().unwrap(), signed[1..65].to_vec()));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        first_error(self.errors())?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(ISSUE_TYPE)?;
+
+        Ok(IssueTransaction {
+            sender_public_key: reader.public_key()?,
+            name: reader.string()?,
+            description: reader.string()?,
+            quantity: reader.u64()?,
+            decimals: reader.u8()?,
+            reissuable: reader.bool()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+impl Transaction for IssueTransaction {
+    /// Serialize the issue as
+    /// `type | sender public key | name | description | quantity | decimals | reissuable | fee | timestamp`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![ISSUE_TYPE],
+            self.sender_public_key.clone(),
+            serialize_field(self.name.as_bytes().to_vec())?,
+            serialize_field(self.description.as_bytes().to_vec())?,
+            serialize_integer(self.quantity),
+            vec![self.decimals],
+            vec![self.reissuable as u8],
+            serialize_integer(self.fee),
+            serialize_integer(self.timestamp),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `type | signature | body bytes`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([vec![ISSUE_TYPE], signature, self.body_bytes()?].concat())
+    }
+}
+
+/**
+# Sign Issue
+## Build, validate and sign an issue transaction
+
+- Receive your private key and the token fields and return the signed transaction like bytes
+- Throw if the name, description or decimals are invalid
+- A `fee` of `0` is replaced by the suggested fee, see `suggest_fee`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+
+const signed: Uint8Array = wasm.signIssue(
+    privateKey, publicKey, "Lunes Token", "My first token",
+    1000n, 2, true, 100000000n, BigInt(Date.now())
+)
+```
+*/
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "signIssue")]
+pub fn sign_issue(
+    private_key: Vec<u8>,
+    sender_public_key: Vec<u8>,
+    name: String,
+    description: String,
+    quantity: u64,
+    decimals: u8,
+    reissuable: bool,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let mut tx = IssueTransaction {
+        sender_public_key,
+        name,
+        description,
+        quantity,
+        decimals,
+        reissuable,
+        fee,
+        timestamp,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee(&tx);
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
@@ -0,0 +1,390 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+
+use crate::transactions::parser::{parse_transaction, SignedTransaction, TransactionKind};
+use crate::transactions::recipient::Recipient;
+use crate::transactions::{
+    TransactionError, BURN_TYPE, CREATE_ALIAS_TYPE, DATA_TYPE, DATA_VERSION, ISSUE_TYPE,
+    LEASE_CANCEL_TYPE, LEASE_TYPE, MASS_TRANSFER_TYPE, MASS_TRANSFER_VERSION, REISSUE_TYPE,
+    TRANSFER_TYPE,
+};
+use crate::utils::base58::vec_to_b58;
+
+/// Byte fields encoded in base58, like the node shows them
+pub(crate) mod base58 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&vec_to_b58(bytes.to_vec()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let b58 = String::deserialize(deserializer)?;
+        bs58::decode(&b58).into_vec().map_err(D::Error::custom)
+    }
+}
+
+/// Optional asset ids encoded in base58, `null` for LUNES
+pub(crate) mod base58_option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => base58::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(b58) if !b58.is_empty() => bs58::decode(&b58)
+                .into_vec()
+                .map(Some)
+                .map_err(D::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Binary data entries encoded like `base64:<value>`
+pub(crate) mod base64_prefixed {
+    use super::*;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("base64:{}", STANDARD.encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.strip_prefix("base64:") {
+            Some(b64) => STANDARD.decode(b64).map_err(D::Error::custom),
+            None => Err(D::Error::custom("binary value must start with base64:")),
+        }
+    }
+}
+
+/// Chain id of an alias when the JSON does not have one, the node does not send it
+pub(crate) fn mainnet() -> u8 {
+    Network::Mainnet.chain_id()
+}
+
+/// Add the `chainId` of the network to a JSON object that does not have one
+///
+/// - Only the alias of a create alias reads it, the other types ignore it
+pub(crate) fn with_chain(mut json: Value, network: Network) -> Value {
+    if let Value::Object(fields) = &mut json {
+        fields
+            .entry("chainId")
+            .or_insert_with(|| network.chain_id().into());
+    }
+    json
+}
+
+/// Error of a JSON that does not match the transaction
+fn invalid(msg: impl std::fmt::Display) -> serde_json::Error {
+    <serde_json::Error as serde::de::Error>::custom(msg)
+}
+
+impl Serialize for Recipient {
+    /// Serialize the recipient like `Display`, base58 address or `alias:<chain>:<name>`
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Recipient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl TransactionKind {
+    /// Fields of the transaction plus `type` and `version`, without id and signature
+    fn to_json(&self) -> Result<Map<String, Value>, serde_json::Error> {
+        let value = match self {
+            TransactionKind::Issue(tx) => serde_json::to_value(tx),
+            TransactionKind::Transfer(tx) => serde_json::to_value(tx),
+            TransactionKind::Reissue(tx) => serde_json::to_value(tx),
+            TransactionKind::Burn(tx) => serde_json::to_value(tx),
+            TransactionKind::Lease(tx) => serde_json::to_value(tx),
+            TransactionKind::LeaseCancel(tx) => serde_json::to_value(tx),
+            TransactionKind::CreateAlias(tx) => serde_json::to_value(tx),
+            TransactionKind::MassTransfer(tx) => serde_json::to_value(tx),
+            TransactionKind::Data(tx) => serde_json::to_value(tx),
+        }?;
+
+        let mut json = match value {
+            Value::Object(json) => json,
+            _ => return Err(invalid("transaction is not a JSON object")),
+        };
+        json.insert("type".to_string(), self.type_id().into());
+        match self {
+            TransactionKind::MassTransfer(_) => {
+                json.insert("version".to_string(), MASS_TRANSFER_VERSION.into());
+            }
+            TransactionKind::Data(_) => {
+                json.insert("version".to_string(), DATA_VERSION.into());
+            }
+            _ => {}
+        }
+
+        Ok(json)
+    }
+
+    /// Dispatch on the `type` and `version` fields into a typed transaction
+    fn from_json(json: Value) -> Result<Self, serde_json::Error> {
+        let type_id = json
+            .get("type")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| invalid("missing transaction type"))?;
+        let type_id = u8::try_from(type_id)
+            .map_err(|_| invalid(format!("unexpected transaction type {}", type_id)))?;
+
+        let version = json.get("version").and_then(Value::as_u64);
+        let expect_version = |expected: u8| match version {
+            Some(version) if version != expected as u64 => {
+                Err(invalid(TransactionError::InvalidVersion(version as u8)))
+            }
+            _ => Ok(()),
+        };
+
+        Ok(match type_id {
+            ISSUE_TYPE => TransactionKind::Issue(serde_json::from_value(json)?),
+            TRANSFER_TYPE => TransactionKind::Transfer(serde_json::from_value(json)?),
+            REISSUE_TYPE => TransactionKind::Reissue(serde_json::from_value(json)?),
+            BURN_TYPE => TransactionKind::Burn(serde_json::from_value(json)?),
+            LEASE_TYPE => TransactionKind::Lease(serde_json::from_value(json)?),
+            LEASE_CANCEL_TYPE => TransactionKind::LeaseCancel(serde_json::from_value(json)?),
+            CREATE_ALIAS_TYPE => TransactionKind::CreateAlias(serde_json::from_value(json)?),
+            MASS_TRANSFER_TYPE => {
+                expect_version(MASS_TRANSFER_VERSION)?;
+                TransactionKind::MassTransfer(serde_json::from_value(json)?)
+            }
+            DATA_TYPE => {
+                expect_version(DATA_VERSION)?;
+                TransactionKind::Data(serde_json::from_value(json)?)
+            }
+            byte => return Err(invalid(TransactionError::InvalidType(byte))),
+        })
+    }
+
+    fn has_proofs(&self) -> bool {
+        matches!(
+            self,
+            TransactionKind::MassTransfer(_) | TransactionKind::Data(_)
+        )
+    }
+}
+
+impl Serialize for TransactionKind {
+    /// Serialize the unsigned transaction in the node JSON shape
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TransactionKind::from_json(Value::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for SignedTransaction {
+    /// Serialize the transaction with its `id` and its `signature` or `proofs`, ready to broadcast
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut json = self.transaction.to_json().map_err(S::Error::custom)?;
+        let signature = Value::String(vec_to_b58(self.signature.clone()));
+
+        json.insert(
+            "id".to_string(),
+            self.id().map_err(S::Error::custom)?.into(),
+        );
+        if self.transaction.has_proofs() {
+            json.insert("proofs".to_string(), Value::Array(vec![signature]));
+        } else {
+            json.insert("signature".to_string(), signature);
+        }
+
+        json.serialize(serializer)
+    }
+}
+
+impl SignedTransaction {
+    /**
+    # From JSON
+    ## Deserialize the JSON of the node for a network
+
+    - The node does not send the chain of an alias, it is the chain of the network
+    - `Deserialize` alone reads an alias without `chainId` as mainnet
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::Network;
+    use lunesrs::transactions::alias::{Alias, CreateAliasTransaction};
+    use lunesrs::transactions::parser::{parse_transaction, SignedTransaction};
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+    let prvk = to_private_key(vec![1; 32]);
+    let tx = CreateAliasTransaction {
+        sender_public_key: to_public_key(prvk.clone()),
+        alias: Alias::new(Network::Testnet, "lunes"),
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = parse_transaction(tx.sign(prvk).unwrap()).unwrap();
+    let json = serde_json::to_value(&signed).unwrap();
+
+    assert_eq!(SignedTransaction::from_json(json.clone(), Network::Testnet).unwrap(), signed);
+    assert_eq!(false, SignedTransaction::from_json(json, Network::Mainnet).unwrap().verify());
+    ```
+    */
+    pub fn from_json(
+        json: Value,
+        network: impl Into<Network>,
+    ) -> Result<SignedTransaction, serde_json::Error> {
+        SignedTransaction::deserialize(with_chain(json, network.into()))
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedTransaction {
+    /// Deserialize a transaction with a `signature` or a single proof, the `id` is ignored
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = Value::deserialize(deserializer)?;
+        let signature = match (json.get("signature"), json.get("proofs")) {
+            (Some(signature), _) => signature.clone(),
+            (None, Some(Value::Array(proofs))) if proofs.len() == 1 => proofs[0].clone(),
+            (None, Some(Value::Array(proofs))) => {
+                return Err(D::Error::custom(TransactionError::InvalidProofs(
+                    proofs.len() as u16,
+                )))
+            }
+            _ => return Err(D::Error::custom("missing signature or proofs")),
+        };
+
+        Ok(SignedTransaction {
+            signature: base58::deserialize(signature).map_err(D::Error::custom)?,
+            transaction: TransactionKind::from_json(json).map_err(D::Error::custom)?,
+        })
+    }
+}
+
+/**
+# Transaction to JSON
+## Convert a signed transaction to the JSON of the node
+
+- Receive the signed transaction like bytes, parse and verify it
+- Return the JSON that `POST /transactions/broadcast` accepts
+- Byte fields are encoded in base58, amounts are integers
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const signed: Uint8Array = wasm.signTransfer(...)
+const json: string = wasm.transactionToJson(signed)
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::json::transaction_to_json;
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let prvk = to_private_key(vec![1; 32]);
+let tx = TransferTransaction {
+    sender_public_key: to_public_key(prvk.clone()),
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    asset_id: None,
+    fee_asset: None,
+    amount: 100000000,
+    fee: 1000000,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+let json: serde_json::Value =
+    serde_json::from_str(&transaction_to_json(tx.sign(prvk).unwrap()).unwrap()).unwrap();
+
+assert_eq!(json["type"], 4);
+assert_eq!(json["amount"], 100000000);
+assert_eq!(json["recipient"], "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+```
+*/
+#[wasm_bindgen(js_name = "transactionToJson")]
+pub fn transaction_to_json(signed_bytes: Vec<u8>) -> Result<String, LunesError> {
+    let tx = parse_transaction(signed_bytes)?;
+    serde_json::to_string(&tx).map_err(|e| LunesError::Json(e.to_string()))
+}
+
+/**
+# Transaction from JSON
+## Convert the JSON of the node to a signed transaction
+
+- Receive the JSON of a signed transaction and the chain id of its network and return it like bytes
+- The node does not send the chain of an alias, it is the chain of the network, see `SignedTransaction::from_json`
+- Throw if the JSON is malformed or the signature does not match the sender
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const json: string = await fetch(`${node}/transactions/info/${id}`).then(r => r.text())
+const mainnet = 1
+const signed: Uint8Array = wasm.transactionFromJson(json, mainnet)
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::transactions::json::{transaction_from_json, transaction_to_json};
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let prvk = to_private_key(vec![1; 32]);
+let tx = LeaseTransaction {
+    sender_public_key: to_public_key(prvk.clone()),
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    amount: 100000000,
+    fee: 100000,
+    timestamp: 1528077600000,
+};
+let signed = tx.sign(prvk, Network::Mainnet).unwrap();
+
+let json = transaction_to_json(signed.clone()).unwrap();
+
+assert_eq!(transaction_from_json(json, 1).unwrap(), signed);
+```
+*/
+#[wasm_bindgen(js_name = "transactionFromJson")]
+pub fn transaction_from_json(json: String, chain_id: u8) -> Result<Vec<u8>, LunesError> {
+    let tx = serde_json::from_str(&json)
+        .and_then(|json| SignedTransaction::from_json(json, chain_id))
+        .map_err(|e| LunesError::Json(e.to_string()))?;
+
+    if tx.verify() {
+        tx.signed_bytes().map_err(LunesError::from)
+    } else {
+        Err(LunesError::from(TransactionError::InvalidSignature))
+    }
+}
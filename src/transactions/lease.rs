@@ -0,0 +1,326 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+
+use crate::transactions::fee::{suggest_fee, suggest_fee_with, SmartScripts};
+use crate::transactions::reader::Reader;
+use crate::transactions::recipient::Recipient;
+use crate::transactions::{
+    Transaction, TransactionError, ID_LENGTH, LEASE_CANCEL_TYPE, LEASE_TYPE,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// Lease of `amount` LUNES from the sender to a node address or alias
+///
+/// - The LUNES never leave the sender, only the generating balance of the node grows
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct LeaseTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    pub recipient: Recipient,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl LeaseTransaction {
+    /**
+    # Sign
+    ## Validate and sign the lease with your private key
+
+    - Fail with `InvalidRecipient` if the recipient is not a valid address or alias of the network
+    - The network is a `Network` or a chain id
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::Network;
+    use lunesrs::transactions::lease::LeaseTransaction;
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = LeaseTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk.clone(), Network::Mainnet).unwrap();
+    let body = tx.body_bytes().unwrap();
+
+    assert_eq!(true, validate_signature(pubk, body.clone(), signed[body.len()..].to_vec()));
+    assert_eq!(true, tx.sign(prvk, Network::Testnet).is_err());
+    ```
+    */
+    pub fn sign(
+        &self,
+        private_key: impl Into<PrivateKey>,
+        network: impl Into<Network>,
+    ) -> Result<Vec<u8>, TransactionError> {
+        self.recipient.validate(network)?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(LEASE_TYPE)?;
+
+        Ok(LeaseTransaction {
+            sender_public_key: reader.public_key()?,
+            recipient: Recipient::read(reader)?,
+            amount: reader.u64()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+impl Transaction for LeaseTransaction {
+    /// Serialize the lease as `type | sender public key | recipient | amount | fee | timestamp`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![LEASE_TYPE],
+            self.sender_public_key.clone(),
+            self.recipient.to_bytes()?,
+            serialize_integer(self.amount),
+            serialize_integer(self.fee),
+            serialize_integer(self.timestamp),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | signature`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, signature].concat())
+    }
+}
+
+/// Cancel of the lease with id `lease_id`, only the lease sender can cancel it
+///
+/// - The lease id is `LeaseTransaction::id_bytes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct LeaseCancelTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(alias = "txId", with = "crate::transactions::json::base58")
+    )]
+    pub lease_id: Vec<u8>,
+    pub fee: u64,
+    pub timestamp: u64,
+}
+
+impl LeaseCancelTransaction {
+    /**
+    # Sign
+    ## Sign the lease cancel with your private key
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::lease::{LeaseCancelTransaction, LeaseTransaction};
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let lease = LeaseTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let cancel = LeaseCancelTransaction {
+        sender_public_key: pubk.clone(),
+        lease_id: lease.id_bytes().unwrap(),
+        fee: 100000,
+        timestamp: 1528077700000,
+    };
+    let signed = cancel.sign(prvk).unwrap();
+    let body = cancel.body_bytes().unwrap();
+
+    assert_eq!(true, validate_signature(pubk, body.clone(), signed[body.len()..].to_vec()));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(LEASE_CANCEL_TYPE)?;
+
+        Ok(LeaseCancelTransaction {
+            sender_public_key: reader.public_key()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+            lease_id: reader.bytes(ID_LENGTH as usize)?,
+        })
+    }
+}
+
+impl Transaction for LeaseCancelTransaction {
+    /// Serialize the cancel as `type | sender public key | fee | timestamp | lease id`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![LEASE_CANCEL_TYPE],
+            self.sender_public_key.clone(),
+            serialize_integer(self.fee),
+            serialize_integer(self.timestamp),
+            self.lease_id.clone(),
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | signature`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, signature].concat())
+    }
+}
+
+/**
+# Sign Lease
+## Build, validate and sign a lease transaction
+
+- The recipient is a base58 address or an alias like `alias:1:lunes`
+- Throw if the recipient is not a valid address or alias of the network
+- A `fee` of `0` is replaced by the suggested fee of the network, see `suggest_fee_with`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::transactions::lease::sign_lease;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let private_key = to_private_key(vec![1; 32]);
+let public_key = to_public_key(private_key.clone());
+let node = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string();
+
+let signed = sign_lease(private_key, Network::Mainnet, public_key, node, 100000000, 0, 1528077600000);
+
+assert_eq!(true, signed.is_ok());
+```
+*/
+pub fn sign_lease(
+    private_key: Vec<u8>,
+    network: impl Into<Network>,
+    sender_public_key: Vec<u8>,
+    recipient: String,
+    amount: u64,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let network: Network = network.into();
+    let sign = || -> Result<Vec<u8>, TransactionError> {
+        let mut tx = LeaseTransaction {
+            sender_public_key,
+            recipient: recipient.parse()?,
+            amount,
+            fee,
+            timestamp,
+        };
+        if tx.fee == 0 {
+            tx.fee = suggest_fee_with(&tx, &network.fee_defaults(), SmartScripts::default());
+        }
+
+        tx.sign(private_key, network)
+    };
+
+    sign().map_err(LunesError::from)
+}
+
+/**
+# Sign Lease
+## `sign_lease` for JavaScript, with the chain id of the network
+
+- `chainId` is the chain id of `Network::chain_id`, take it from `chainIdOf("mainnet")`
+- Throw if the recipient is not a valid address or alias of the chain
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const node = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj"
+const mainnet: number = wasm.chainIdOf("mainnet")
+
+const signed: Uint8Array = wasm.signLease(
+    privateKey, mainnet, publicKey, node, 100000000n, 100000n, BigInt(Date.now())
+)
+```
+*/
+#[wasm_bindgen(js_name = "signLease")]
+pub fn sign_lease_js(
+    private_key: Vec<u8>,
+    chain_id: u8,
+    sender_public_key: Vec<u8>,
+    recipient: String,
+    amount: u64,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    sign_lease(
+        private_key,
+        Network::from(chain_id),
+        sender_public_key,
+        recipient,
+        amount,
+        fee,
+        timestamp,
+    )
+}
+
+/**
+# Sign Lease Cancel
+## Build and sign a lease cancel transaction
+
+- A `fee` of `0` is replaced by the suggested fee, see `suggest_fee`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const leaseId: Uint8Array = wasm.base58ToArray("2bkjzFqTMM3cQpbgGYKE8r7J73SrXFH8YfxFBRBterLt")
+
+const signed: Uint8Array = wasm.signLeaseCancel(privateKey, publicKey, leaseId, 100000n, BigInt(Date.now()))
+```
+*/
+#[wasm_bindgen(js_name = "signLeaseCancel")]
+pub fn sign_lease_cancel(
+    private_key: Vec<u8>,
+    sender_public_key: Vec<u8>,
+    lease_id: Vec<u8>,
+    fee: u64,
+    timestamp: u64,
+) -> Result<Vec<u8>, LunesError> {
+    let mut tx = LeaseCancelTransaction {
+        sender_public_key,
+        lease_id,
+        fee,
+        timestamp,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee(&tx);
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
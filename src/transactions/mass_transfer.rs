@@ -0,0 +1,300 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+
+use crate::transactions::fee::{suggest_fee_with, SmartScripts};
+use crate::transactions::reader::Reader;
+use crate::transactions::recipient::Recipient;
+use crate::transactions::validation::first_error;
+use crate::transactions::{
+    serialize_asset, serialize_count, serialize_field, serialize_proofs, Transaction,
+    TransactionError, MASS_TRANSFER_TYPE, MASS_TRANSFER_VERSION, MAX_MASS_TRANSFER_COUNT,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// One payment of a mass transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Transfer {
+    pub recipient: Recipient,
+    pub amount: u64,
+}
+
+/// Transfer of the same asset from the sender to many recipients
+///
+/// - `asset_id` is `None` for LUNES
+/// - The fee is always paid in LUNES, see `Fee`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct MassTransferTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::transactions::json::base58_option")
+    )]
+    pub asset_id: Option<Vec<u8>>,
+    pub transfers: Vec<Transfer>,
+    pub fee: u64,
+    pub timestamp: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::transactions::json::base58")
+    )]
+    pub attachment: Vec<u8>,
+}
+
+impl MassTransferTransaction {
+    /// Transfers that the node refuses, see `Validate`
+    ///
+    /// - Each recipient must be valid for the chain, see `Recipient::validate`
+    /// - Each recipient must appear only once
+    pub(crate) fn errors(&self, network: Network) -> Vec<TransactionError> {
+        if self.transfers.len() > MAX_MASS_TRANSFER_COUNT {
+            return vec![TransactionError::TooManyTransfers(self.transfers.len())];
+        }
+
+        let mut errors = vec![];
+        for (i, transfer) in self.transfers.iter().enumerate() {
+            if let Err(e) = transfer.recipient.validate(network) {
+                errors.push(e);
+            }
+            if self.transfers[..i]
+                .iter()
+                .any(|x| x.recipient == transfer.recipient)
+            {
+                errors.push(TransactionError::DuplicateRecipient(
+                    transfer.recipient.to_string(),
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /**
+    # Sign
+    ## Validate and sign the mass transfer with your private key
+
+    - Fail if a recipient is not valid for the network or duplicated, or there are too many transfers
+    - The network is a `Network` or a chain id
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::Network;
+    use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = MassTransferTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: None,
+        transfers: vec![Transfer {
+            recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+            amount: 100000000,
+        }],
+        fee: 150000,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    };
+    let signed = tx.sign(prvk.clone(), Network::Mainnet).unwrap();
+    let signature = signed[signed.len() - 64..].to_vec();
+
+    assert_eq!(true, validate_signature(pubk, tx.body_bytes().unwrap(), signature));
+    assert_eq!(true, tx.sign(prvk, Network::Testnet).is_err());
+    ```
+    */
+    pub fn sign(
+        &self,
+        private_key: impl Into<PrivateKey>,
+        network: impl Into<Network>,
+    ) -> Result<Vec<u8>, TransactionError> {
+        first_error(self.errors(network.into()))?;
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(MASS_TRANSFER_TYPE)?;
+        reader.expect_version(MASS_TRANSFER_VERSION)?;
+        let sender_public_key = reader.public_key()?;
+        let asset_id = reader.asset()?;
+        let transfers = (0..reader.u16()?)
+            .map(|_| {
+                Ok(Transfer {
+                    recipient: Recipient::read(reader)?,
+                    amount: reader.u64()?,
+                })
+            })
+            .collect::<Result<Vec<Transfer>, TransactionError>>()?;
+
+        Ok(MassTransferTransaction {
+            sender_public_key,
+            asset_id,
+            transfers,
+            timestamp: reader.u64()?,
+            fee: reader.u64()?,
+            attachment: reader.array()?,
+        })
+    }
+}
+
+impl Transaction for MassTransferTransaction {
+    /// Serialize the mass transfer as
+    /// `type | version | sender public key | asset | count | transfers | timestamp | fee | attachment`
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        let transfers = self
+            .transfers
+            .iter()
+            .map(|x| Ok([x.recipient.to_bytes()?, serialize_integer(x.amount)].concat()))
+            .collect::<Result<Vec<Vec<u8>>, TransactionError>>()?;
+
+        Ok([
+            vec![MASS_TRANSFER_TYPE, MASS_TRANSFER_VERSION],
+            self.sender_public_key.clone(),
+            serialize_asset(&self.asset_id),
+            serialize_count(self.transfers.len())?,
+            transfers.concat(),
+            serialize_integer(self.timestamp),
+            serialize_integer(self.fee),
+            serialize_field(self.attachment.clone())?,
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `body bytes | proofs`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([self.body_bytes()?, serialize_proofs(signature)?].concat())
+    }
+}
+
+/**
+# Sign Mass Transfer
+## Build, validate and sign a mass transfer transaction
+
+- `recipients` are base58 addresses or aliases and `amounts` their amounts, in the same order
+- The fee is the suggested fee of the network for the number of transfers, see `suggest_fee_with`
+- Throw if a recipient is invalid or duplicated, or there are too many transfers
+- Throw if the attachment has more than 65535 bytes
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::transactions::mass_transfer::sign_mass_transfer;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let private_key = to_private_key(vec![1; 32]);
+let public_key = to_public_key(private_key.clone());
+let recipients = vec!["37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string()];
+
+let signed = sign_mass_transfer(
+    private_key, Network::Mainnet, public_key, vec![], recipients, vec![100000000], 1528077600000, vec![]
+);
+
+assert_eq!(true, signed.is_ok());
+```
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn sign_mass_transfer(
+    private_key: Vec<u8>,
+    network: impl Into<Network>,
+    sender_public_key: Vec<u8>,
+    asset_id: Vec<u8>,
+    recipients: Vec<String>,
+    amounts: Vec<u64>,
+    timestamp: u64,
+    attachment: Vec<u8>,
+) -> Result<Vec<u8>, LunesError> {
+    if recipients.len() != amounts.len() {
+        return Err(LunesError::from(TransactionError::MismatchedTransfers(
+            recipients.len(),
+            amounts.len(),
+        )));
+    }
+
+    let network: Network = network.into();
+    let sign = || {
+        let mut tx = MassTransferTransaction {
+            sender_public_key,
+            asset_id: if asset_id.is_empty() {
+                None
+            } else {
+                Some(asset_id)
+            },
+            fee: 0,
+            transfers: recipients
+                .iter()
+                .zip(amounts)
+                .map(|(recipient, amount)| {
+                    Ok(Transfer {
+                        recipient: recipient.parse()?,
+                        amount,
+                    })
+                })
+                .collect::<Result<Vec<Transfer>, TransactionError>>()?,
+            timestamp,
+            attachment,
+        };
+        tx.fee = suggest_fee_with(&tx, &network.fee_defaults(), SmartScripts::default());
+
+        tx.sign(private_key, network)
+    };
+
+    sign().map_err(LunesError::from)
+}
+
+/**
+# Sign Mass Transfer
+## `sign_mass_transfer` for JavaScript, with the chain id of the network
+
+- `chainId` is the chain id of `Network::chain_id`, take it from `chainIdOf("mainnet")`
+- Throw if a recipient is invalid or duplicated, or there are too many transfers
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const mainnet: number = wasm.chainIdOf("mainnet")
+
+const signed: Uint8Array = wasm.signMassTransfer(
+    privateKey, mainnet, publicKey, new Uint8Array(),
+    ["37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj"], new BigUint64Array([100000000n]),
+    BigInt(Date.now()), new Uint8Array()
+)
+```
+*/
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "signMassTransfer")]
+pub fn sign_mass_transfer_js(
+    private_key: Vec<u8>,
+    chain_id: u8,
+    sender_public_key: Vec<u8>,
+    asset_id: Vec<u8>,
+    recipients: Vec<String>,
+    amounts: Vec<u64>,
+    timestamp: u64,
+    attachment: Vec<u8>,
+) -> Result<Vec<u8>, LunesError> {
+    sign_mass_transfer(
+        private_key,
+        Network::from(chain_id),
+        sender_public_key,
+        asset_id,
+        recipients,
+        amounts,
+        timestamp,
+        attachment,
+    )
+}
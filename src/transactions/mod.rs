@@ -0,0 +1,439 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+
+use crate::utils::base58::vec_to_b58;
+use crate::utils::crypto::to_blake2b32b;
+use crate::utils::serialize::MAX_ARRAY_LENGTH;
+
+/// Creation of an alias for an address
+pub mod alias;
+/// Burn of an amount of a token
+pub mod burn;
+/// Storage of typed key/value entries in the account of the sender
+pub mod data;
+/// Minimum fees of every transaction type
+pub mod fee;
+/// Issue of a new token
+pub mod issue;
+/// JSON of the node REST API for every transaction
+#[cfg(feature = "serde")]
+pub mod json;
+/// Lease of LUNES to a node and its cancel
+pub mod lease;
+/// Transfer of LUNES or tokens to many addresses at once
+pub mod mass_transfer;
+/// Decode signed transactions from bytes
+pub mod parser;
+/// Read the Lunes binary layout back
+pub(crate) mod reader;
+/// Address or alias that receives a transaction
+pub mod recipient;
+/// Reissue of an existing token
+pub mod reissue;
+/// Transfer of LUNES or tokens to an address
+pub mod transfer;
+/// Rules the node checks before it accepts a transaction
+pub mod validation;
+
+pub const ISSUE_TYPE: u8 = 3;
+pub const TRANSFER_TYPE: u8 = 4;
+pub const REISSUE_TYPE: u8 = 5;
+pub const BURN_TYPE: u8 = 6;
+pub const LEASE_TYPE: u8 = 8;
+pub const LEASE_CANCEL_TYPE: u8 = 9;
+pub const CREATE_ALIAS_TYPE: u8 = 10;
+pub const MASS_TRANSFER_TYPE: u8 = 11;
+pub const DATA_TYPE: u8 = 12;
+
+pub const MASS_TRANSFER_VERSION: u8 = 1;
+pub const MAX_MASS_TRANSFER_COUNT: usize = 100;
+
+pub const DATA_VERSION: u8 = 1;
+pub const MAX_DATA_ENTRIES: usize = 100;
+pub const MAX_DATA_KEY_LENGTH: usize = 100;
+pub const MAX_DATA_VALUE_LENGTH: usize = 32767;
+pub const MAX_DATA_TRANSACTION_BYTES: usize = 150 * 1024;
+
+/// Amounts and fees are signed 64 bits integers in the node
+pub const MAX_AMOUNT: u64 = i64::MAX as u64;
+pub const MAX_ATTACHMENT_LENGTH: usize = 140;
+/// Node refuses transactions older than 2 hours
+pub const MAX_TIMESTAMP_PAST: u64 = 2 * 60 * 60 * 1000;
+/// Node refuses transactions more than 90 minutes in the future
+pub const MAX_TIMESTAMP_FUTURE: u64 = 90 * 60 * 1000;
+
+pub const SIGNATURE_LENGTH: u8 = 64;
+pub const PUBLIC_KEY_LENGTH: u8 = 32;
+pub const ASSET_ID_LENGTH: u8 = 32;
+pub const ID_LENGTH: u8 = 32;
+pub const PROOFS_VERSION: u8 = 1;
+
+pub const MIN_ASSET_NAME_LENGTH: usize = 4;
+pub const MAX_ASSET_NAME_LENGTH: usize = 16;
+pub const MAX_ASSET_DESCRIPTION_LENGTH: usize = 1000;
+pub const MAX_ASSET_DECIMALS: u8 = 8;
+
+pub const ALIAS_VERSION: u8 = 2;
+pub const MIN_ALIAS_LENGTH: usize = 4;
+pub const MAX_ALIAS_LENGTH: usize = 30;
+pub const ALIAS_ALPHABET: &str = "-.0123456789@_abcdefghijklmnopqrstuvwxyz";
+
+/// Reasons a transaction can not be built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionError {
+    /// Asset name must have between 4 and 16 bytes
+    InvalidNameLength(usize),
+    /// Asset description must have at most 1000 bytes
+    InvalidDescriptionLength(usize),
+    /// Asset decimals must be between 0 and 8
+    InvalidDecimals(u8),
+    /// Asset id must have 32 bytes
+    InvalidAssetId(usize),
+    /// Recipient is not a valid address or alias of the chain
+    InvalidRecipient(String),
+    /// Recipient appears more than once in a mass transfer
+    DuplicateRecipient(String),
+    /// Mass transfer must have at most 100 transfers
+    TooManyTransfers(usize),
+    /// Recipients and amounts of a mass transfer must have the same length
+    MismatchedTransfers(usize, usize),
+    /// Alias must have between 4 and 30 characters of the alias alphabet
+    InvalidAlias(String),
+    /// Data transaction must have at most 100 entries
+    TooManyEntries(usize),
+    /// Data key must have between 1 and 100 bytes
+    InvalidKeyLength(String),
+    /// Data key appears more than once
+    DuplicateKey(String),
+    /// Binary or string value of the key must have at most 32767 bytes
+    ValueTooLong(String),
+    /// Data transaction must have at most 150 KB
+    TransactionTooLarge(usize),
+    /// Bytes ended before the field that starts at the offset was complete
+    UnexpectedEnd(usize),
+    /// Bytes remain after the end of the transaction
+    TrailingBytes(usize),
+    /// Transaction type byte is not the expected one
+    InvalidType(u8),
+    /// Transaction version byte is not supported
+    InvalidVersion(u8),
+    /// Data entry type byte is not 0, 1, 2 or 3
+    InvalidDataType(u8),
+    /// Boolean byte is not 0 or 1
+    InvalidBoolean(u8),
+    /// String at the offset is not valid UTF-8
+    InvalidString(usize),
+    /// Proofs must have a single signature
+    InvalidProofs(u16),
+    /// Signature does not match the sender public key and the body bytes
+    InvalidSignature,
+    /// Timestamp is outside the window the node accepts around its clock
+    InvalidTimestamp(u64),
+    /// Amount must be greater than 0
+    ZeroAmount,
+    /// Fee must be greater than 0
+    ZeroFee,
+    /// Amount, quantity or sum of the amounts is greater than `MAX_AMOUNT`
+    AmountOverflow,
+    /// Fee is greater than `MAX_AMOUNT`
+    FeeOverflow,
+    /// Attachment must have at most 140 bytes
+    AttachmentTooLong(usize),
+    /// Lease recipient is the address of the sender
+    LeaseToSelf,
+    /// Variable-length field or list has more than 65535 items, its length does not fit in a u16
+    ArrayTooLong(usize),
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TransactionError::InvalidNameLength(len) => write!(
+                f,
+                "asset name must have between {} and {} bytes, got {}",
+                MIN_ASSET_NAME_LENGTH, MAX_ASSET_NAME_LENGTH, len
+            ),
+            TransactionError::InvalidDescriptionLength(len) => write!(
+                f,
+                "asset description must have at most {} bytes, got {}",
+                MAX_ASSET_DESCRIPTION_LENGTH, len
+            ),
+            TransactionError::InvalidDecimals(decimals) => write!(
+                f,
+                "asset decimals must be between 0 and {}, got {}",
+                MAX_ASSET_DECIMALS, decimals
+            ),
+            TransactionError::InvalidAssetId(len) => write!(
+                f,
+                "asset id must have {} bytes, got {}",
+                ASSET_ID_LENGTH, len
+            ),
+            TransactionError::InvalidRecipient(recipient) => write!(
+                f,
+                "recipient {} is not a valid address or alias of the chain",
+                recipient
+            ),
+            TransactionError::DuplicateRecipient(recipient) => {
+                write!(f, "recipient {} appears more than once", recipient)
+            }
+            TransactionError::TooManyTransfers(count) => write!(
+                f,
+                "mass transfer must have at most {} transfers, got {}",
+                MAX_MASS_TRANSFER_COUNT, count
+            ),
+            TransactionError::MismatchedTransfers(recipients, amounts) => write!(
+                f,
+                "recipients and amounts must have the same length, got {} and {}",
+                recipients, amounts
+            ),
+            TransactionError::InvalidAlias(alias) => write!(
+                f,
+                "alias {:?} must have between {} and {} characters of {:?}",
+                alias, MIN_ALIAS_LENGTH, MAX_ALIAS_LENGTH, ALIAS_ALPHABET
+            ),
+            TransactionError::TooManyEntries(count) => write!(
+                f,
+                "data transaction must have at most {} entries, got {}",
+                MAX_DATA_ENTRIES, count
+            ),
+            TransactionError::InvalidKeyLength(key) => write!(
+                f,
+                "data key {:?} must have between 1 and {} bytes",
+                key, MAX_DATA_KEY_LENGTH
+            ),
+            TransactionError::DuplicateKey(key) => {
+                write!(f, "data key {:?} appears more than once", key)
+            }
+            TransactionError::ValueTooLong(key) => write!(
+                f,
+                "value of data key {:?} must have at most {} bytes",
+                key, MAX_DATA_VALUE_LENGTH
+            ),
+            TransactionError::TransactionTooLarge(size) => write!(
+                f,
+                "data transaction must have at most {} bytes, got {}",
+                MAX_DATA_TRANSACTION_BYTES, size
+            ),
+            TransactionError::UnexpectedEnd(offset) => {
+                write!(f, "unexpected end of bytes at offset {}", offset)
+            }
+            TransactionError::TrailingBytes(offset) => {
+                write!(f, "unexpected trailing bytes at offset {}", offset)
+            }
+            TransactionError::InvalidType(byte) => {
+                write!(f, "unexpected transaction type {}", byte)
+            }
+            TransactionError::InvalidVersion(byte) => {
+                write!(f, "unsupported transaction version {}", byte)
+            }
+            TransactionError::InvalidDataType(byte) => {
+                write!(f, "unknown data entry type {}", byte)
+            }
+            TransactionError::InvalidBoolean(byte) => {
+                write!(f, "boolean must be 0 or 1, got {}", byte)
+            }
+            TransactionError::InvalidString(offset) => {
+                write!(f, "invalid UTF-8 string at offset {}", offset)
+            }
+            TransactionError::InvalidProofs(count) => {
+                write!(f, "proofs must have a single signature, got {}", count)
+            }
+            TransactionError::InvalidSignature => {
+                write!(f, "signature does not match the sender and the body bytes")
+            }
+            TransactionError::InvalidTimestamp(timestamp) => write!(
+                f,
+                "timestamp {} is outside the window accepted by the node",
+                timestamp
+            ),
+            TransactionError::ZeroAmount => write!(f, "amount must be greater than 0"),
+            TransactionError::ZeroFee => write!(f, "fee must be greater than 0"),
+            TransactionError::AmountOverflow => {
+                write!(f, "amount must be at most {}", MAX_AMOUNT)
+            }
+            TransactionError::FeeOverflow => write!(f, "fee must be at most {}", MAX_AMOUNT),
+            TransactionError::AttachmentTooLong(len) => write!(
+                f,
+                "attachment must have at most {} bytes, got {}",
+                MAX_ATTACHMENT_LENGTH, len
+            ),
+            TransactionError::LeaseToSelf => write!(f, "lease recipient is the sender"),
+            TransactionError::ArrayTooLong(len) => write!(
+                f,
+                "field must have at most {} items, got {}",
+                MAX_ARRAY_LENGTH, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransactionError {}
+
+/**
+# Transaction
+## Common interface of every Lunes transaction
+
+- `body_bytes` are the bytes that get signed
+- `signed_bytes` are the bytes that get broadcast
+- `id` identifies the transaction in the node
+- All of them fail with `ArrayTooLong` if a variable-length field does not fit in its u16 length prefix
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::burn::BurnTransaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::utils::base58::vec_to_b58;
+use lunesrs::utils::crypto::to_blake2b32b;
+
+let tx = BurnTransaction {
+    sender_public_key: vec![1; 32],
+    asset_id: vec![2; 32],
+    amount: 1000,
+    fee: 100000,
+    timestamp: 1528077600000,
+};
+
+assert_eq!(tx.id_bytes().unwrap(), to_blake2b32b(tx.body_bytes().unwrap()));
+assert_eq!(tx.id().unwrap(), vec_to_b58(tx.id_bytes().unwrap()));
+```
+*/
+pub trait Transaction {
+    /// Serialize the transaction in the Lunes binary layout without the signature
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError>;
+
+    /// Assemble the body bytes and the signature in the Lunes binary layout
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError>;
+
+    /// Transaction id like bytes, the *`blake 2b 32bytes`* hash of the body bytes
+    fn id_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        self.body_bytes().map(to_blake2b32b)
+    }
+
+    /// Transaction id encoded in base58, like the node shows it
+    fn id(&self) -> Result<String, TransactionError> {
+        self.id_bytes().map(vec_to_b58)
+    }
+}
+
+/**
+# Body Bytes from Signed Bytes
+## Strip the signature of a signed transaction
+
+- The position of the signature depends on the transaction type:
+    - Issue, transfer and reissue: `type | signature | body bytes`
+    - Burn, lease, lease cancel and create alias: `body bytes | signature`
+    - Mass transfer and data: `body bytes | proofs`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::body_from_signed_bytes;
+
+let signed = [vec![4], vec![0; 64], vec![4, 1, 2, 3]].concat();
+
+assert_eq!(body_from_signed_bytes(signed), Ok(vec![4, 1, 2, 3]));
+```
+*/
+pub fn body_from_signed_bytes(signed_bytes: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+    let signature = SIGNATURE_LENGTH as usize;
+    // version | count | length | signature
+    let proofs = 1 + 2 + 2 + signature;
+    let len = signed_bytes.len();
+    let body = match signed_bytes.first() {
+        Some(&ISSUE_TYPE) | Some(&TRANSFER_TYPE) | Some(&REISSUE_TYPE) => {
+            signed_bytes.get(1 + signature..)
+        }
+        Some(&BURN_TYPE)
+        | Some(&LEASE_TYPE)
+        | Some(&LEASE_CANCEL_TYPE)
+        | Some(&CREATE_ALIAS_TYPE) => signed_bytes.get(..len.saturating_sub(signature)),
+        Some(&MASS_TRANSFER_TYPE) | Some(&DATA_TYPE) => {
+            signed_bytes.get(..len.saturating_sub(proofs))
+        }
+        Some(byte) => return Err(TransactionError::InvalidType(*byte)),
+        None => return Err(TransactionError::UnexpectedEnd(0)),
+    };
+
+    match body {
+        Some(body) if !body.is_empty() => Ok(body.to_vec()),
+        _ => Err(TransactionError::UnexpectedEnd(len)),
+    }
+}
+
+/**
+# Transaction ID
+## Compute the id of a signed transaction
+
+- Receive the signed transaction like bytes and return its id encoded in base58
+- Throw if the transaction type is unknown or the bytes are too short
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const signed: Uint8Array = wasm.signTransfer(...)
+const id: string = wasm.transactionId(signed)
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::transaction_id;
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let prvk = to_private_key(vec![1; 32]);
+let tx = TransferTransaction {
+    sender_public_key: to_public_key(prvk.clone()),
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    asset_id: None,
+    fee_asset: None,
+    amount: 100000000,
+    fee: 1000000,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+
+assert_eq!(transaction_id(tx.sign(prvk).unwrap()).unwrap(), tx.id().unwrap());
+```
+*/
+#[wasm_bindgen(js_name = "transactionId")]
+pub fn transaction_id(signed_bytes: Vec<u8>) -> Result<String, LunesError> {
+    body_from_signed_bytes(signed_bytes)
+        .map(|body| vec_to_b58(to_blake2b32b(body)))
+        .map_err(LunesError::from)
+}
+
+/// Prefix a variable-length field with its length, see `serialize_array`
+pub(crate) fn serialize_field(field: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+    Ok([serialize_count(field.len())?, field].concat())
+}
+
+/// Serialize a length or the number of entries or transfers as a big-endian u16
+pub(crate) fn serialize_count(count: usize) -> Result<Vec<u8>, TransactionError> {
+    u16::try_from(count)
+        .map(|count| count.to_be_bytes().to_vec())
+        .map_err(|_| TransactionError::ArrayTooLong(count))
+}
+
+/// Serialize a signature as proofs, `version | count | length | signature`
+pub(crate) fn serialize_proofs(signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+    Ok([
+        vec![PROOFS_VERSION],
+        1u16.to_be_bytes().to_vec(),
+        serialize_field(signature)?,
+    ]
+    .concat())
+}
+
+/// Serialize an optional asset id, `[0]` for LUNES or `[1, ..asset_id]` for tokens
+pub(crate) fn serialize_asset(asset_id: &Option<Vec<u8>>) -> Vec<u8> {
+    match asset_id {
+        Some(id) => [vec![1], id.clone()].concat(),
+        None => vec![0],
+    }
+}
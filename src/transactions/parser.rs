@@ -0,0 +1,234 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::transactions::alias::CreateAliasTransaction;
+use crate::transactions::burn::BurnTransaction;
+use crate::transactions::data::DataTransaction;
+use crate::transactions::issue::IssueTransaction;
+use crate::transactions::lease::{LeaseCancelTransaction, LeaseTransaction};
+use crate::transactions::mass_transfer::MassTransferTransaction;
+use crate::transactions::reader::Reader;
+use crate::transactions::reissue::ReissueTransaction;
+use crate::transactions::transfer::TransferTransaction;
+use crate::transactions::{
+    Transaction, TransactionError, BURN_TYPE, CREATE_ALIAS_TYPE, DATA_TYPE, ISSUE_TYPE,
+    LEASE_CANCEL_TYPE, LEASE_TYPE, MASS_TRANSFER_TYPE, REISSUE_TYPE, TRANSFER_TYPE,
+};
+use crate::wallet::signatures::validate_signature;
+
+/// Any transaction supported by lunesrs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionKind {
+    Issue(IssueTransaction),
+    Transfer(TransferTransaction),
+    Reissue(ReissueTransaction),
+    Burn(BurnTransaction),
+    Lease(LeaseTransaction),
+    LeaseCancel(LeaseCancelTransaction),
+    CreateAlias(CreateAliasTransaction),
+    MassTransfer(MassTransferTransaction),
+    Data(DataTransaction),
+}
+
+impl TransactionKind {
+    fn inner(&self) -> &dyn Transaction {
+        match self {
+            TransactionKind::Issue(tx) => tx,
+            TransactionKind::Transfer(tx) => tx,
+            TransactionKind::Reissue(tx) => tx,
+            TransactionKind::Burn(tx) => tx,
+            TransactionKind::Lease(tx) => tx,
+            TransactionKind::LeaseCancel(tx) => tx,
+            TransactionKind::CreateAlias(tx) => tx,
+            TransactionKind::MassTransfer(tx) => tx,
+            TransactionKind::Data(tx) => tx,
+        }
+    }
+
+    /// Type byte of the transaction
+    pub fn type_id(&self) -> u8 {
+        match self {
+            TransactionKind::Issue(_) => ISSUE_TYPE,
+            TransactionKind::Transfer(_) => TRANSFER_TYPE,
+            TransactionKind::Reissue(_) => REISSUE_TYPE,
+            TransactionKind::Burn(_) => BURN_TYPE,
+            TransactionKind::Lease(_) => LEASE_TYPE,
+            TransactionKind::LeaseCancel(_) => LEASE_CANCEL_TYPE,
+            TransactionKind::CreateAlias(_) => CREATE_ALIAS_TYPE,
+            TransactionKind::MassTransfer(_) => MASS_TRANSFER_TYPE,
+            TransactionKind::Data(_) => DATA_TYPE,
+        }
+    }
+
+    /// Public key of the account that signed the transaction
+    pub fn sender_public_key(&self) -> Vec<u8> {
+        match self {
+            TransactionKind::Issue(tx) => tx.sender_public_key.clone(),
+            TransactionKind::Transfer(tx) => tx.sender_public_key.clone(),
+            TransactionKind::Reissue(tx) => tx.sender_public_key.clone(),
+            TransactionKind::Burn(tx) => tx.sender_public_key.clone(),
+            TransactionKind::Lease(tx) => tx.sender_public_key.clone(),
+            TransactionKind::LeaseCancel(tx) => tx.sender_public_key.clone(),
+            TransactionKind::CreateAlias(tx) => tx.sender_public_key.clone(),
+            TransactionKind::MassTransfer(tx) => tx.sender_public_key.clone(),
+            TransactionKind::Data(tx) => tx.sender_public_key.clone(),
+        }
+    }
+}
+
+impl Transaction for TransactionKind {
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        self.inner().body_bytes()
+    }
+
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        self.inner().signed_bytes(signature)
+    }
+}
+
+/// Transaction together with the signature of its sender
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTransaction {
+    pub transaction: TransactionKind,
+    pub signature: Vec<u8>,
+}
+
+impl SignedTransaction {
+    /// Transaction id encoded in base58, see `Transaction::id`
+    pub fn id(&self) -> Result<String, TransactionError> {
+        self.transaction.id()
+    }
+
+    /// Assemble the signed transaction in the Lunes binary layout
+    pub fn signed_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        self.transaction.signed_bytes(self.signature.clone())
+    }
+
+    /// Check the signature against the sender public key with `validate_signature`
+    ///
+    /// - `false` if the transaction can not be serialized
+    pub fn verify(&self) -> bool {
+        match self.transaction.body_bytes() {
+            Ok(body_bytes) => validate_signature(
+                self.transaction.sender_public_key(),
+                body_bytes,
+                self.signature.clone(),
+            ),
+            Err(_) => false,
+        }
+    }
+}
+
+/**
+# Parse Transaction
+## Decode a signed transaction from bytes
+
+- Dispatch on the type and version bytes into a typed transaction
+- Fail on truncated or malformed input and on trailing bytes
+- Fail with `InvalidSignature` if the signature does not match the sender
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::parser::{parse_transaction, TransactionKind};
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let prvk = to_private_key(vec![1; 32]);
+let tx = TransferTransaction {
+    sender_public_key: to_public_key(prvk.clone()),
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    asset_id: None,
+    fee_asset: None,
+    amount: 100000000,
+    fee: 1000000,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+let signed = tx.sign(prvk).unwrap();
+
+assert_eq!(
+    parse_transaction(signed.clone()).unwrap().transaction,
+    TransactionKind::Transfer(tx)
+);
+assert_eq!(
+    parse_transaction(signed[..100].to_vec()),
+    Err(TransactionError::UnexpectedEnd(100))
+);
+```
+*/
+pub fn parse_transaction(signed_bytes: Vec<u8>) -> Result<SignedTransaction, TransactionError> {
+    let mut reader = Reader::new(&signed_bytes);
+    let signed = match reader.peek()? {
+        ISSUE_TYPE | TRANSFER_TYPE | REISSUE_TYPE => {
+            let type_id = reader.u8()?;
+            let signature = reader.signature()?;
+            let transaction = match type_id {
+                ISSUE_TYPE => TransactionKind::Issue(IssueTransaction::read(&mut reader)?),
+                TRANSFER_TYPE => TransactionKind::Transfer(TransferTransaction::read(&mut reader)?),
+                _ => TransactionKind::Reissue(ReissueTransaction::read(&mut reader)?),
+            };
+
+            SignedTransaction {
+                transaction,
+                signature,
+            }
+        }
+        BURN_TYPE | LEASE_TYPE | LEASE_CANCEL_TYPE | CREATE_ALIAS_TYPE => {
+            let transaction = match reader.peek()? {
+                BURN_TYPE => TransactionKind::Burn(BurnTransaction::read(&mut reader)?),
+                LEASE_TYPE => TransactionKind::Lease(LeaseTransaction::read(&mut reader)?),
+                LEASE_CANCEL_TYPE => {
+                    TransactionKind::LeaseCancel(LeaseCancelTransaction::read(&mut reader)?)
+                }
+                _ => TransactionKind::CreateAlias(CreateAliasTransaction::read(&mut reader)?),
+            };
+
+            SignedTransaction {
+                transaction,
+                signature: reader.signature()?,
+            }
+        }
+        MASS_TRANSFER_TYPE | DATA_TYPE => {
+            let transaction = match reader.peek()? {
+                MASS_TRANSFER_TYPE => {
+                    TransactionKind::MassTransfer(MassTransferTransaction::read(&mut reader)?)
+                }
+                _ => TransactionKind::Data(DataTransaction::read(&mut reader)?),
+            };
+
+            SignedTransaction {
+                transaction,
+                signature: reader.proofs()?,
+            }
+        }
+        byte => return Err(TransactionError::InvalidType(byte)),
+    };
+    reader.finish()?;
+
+    match signed.verify() {
+        true => Ok(signed),
+        false => Err(TransactionError::InvalidSignature),
+    }
+}
+
+/**
+# Verify Transaction
+## Check that signed bytes are a valid transaction with a valid signature
+
+- Receive the signed transaction like bytes and return bool
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const signed: Uint8Array = wasm.signTransfer(...)
+
+true == wasm.verifyTransaction(signed)
+```
+*/
+#[wasm_bindgen(js_name = "verifyTransaction")]
+pub fn verify_transaction(signed_bytes: Vec<u8>) -> bool {
+    parse_transaction(signed_bytes).is_ok()
+}
@@ -0,0 +1,133 @@
+use crate::transactions::{
+    TransactionError, ASSET_ID_LENGTH, PROOFS_VERSION, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH,
+};
+
+/// Cursor over transaction bytes, every read fails with
+/// `TransactionError::UnexpectedEnd` with the offset of the read instead of
+/// panicking on short input
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, offset: 0 }
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<Vec<u8>, TransactionError> {
+        match self.bytes.get(self.offset..self.offset + len) {
+            Some(slice) => {
+                self.offset += len;
+                Ok(slice.to_vec())
+            }
+            None => Err(TransactionError::UnexpectedEnd(self.offset)),
+        }
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, TransactionError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, TransactionError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, TransactionError> {
+        let mut buffer = [0; 8];
+        buffer.copy_from_slice(&self.bytes(8)?);
+        Ok(u64::from_be_bytes(buffer))
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, TransactionError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(TransactionError::InvalidBoolean(byte)),
+        }
+    }
+
+    /// Read an array prefixed by its length, see `serialize_array`
+    pub(crate) fn array(&mut self) -> Result<Vec<u8>, TransactionError> {
+        let len = self.u16()? as usize;
+        self.bytes(len)
+    }
+
+    /// Read an UTF-8 string prefixed by its length
+    pub(crate) fn string(&mut self) -> Result<String, TransactionError> {
+        let offset = self.offset;
+        String::from_utf8(self.array()?).map_err(|_| TransactionError::InvalidString(offset))
+    }
+
+    /// Read the transaction type byte and check it is `expected`
+    pub(crate) fn expect_type(&mut self, expected: u8) -> Result<(), TransactionError> {
+        match self.u8()? {
+            byte if byte == expected => Ok(()),
+            byte => Err(TransactionError::InvalidType(byte)),
+        }
+    }
+
+    /// Read the transaction version byte and check it is `expected`
+    pub(crate) fn expect_version(&mut self, expected: u8) -> Result<(), TransactionError> {
+        match self.u8()? {
+            byte if byte == expected => Ok(()),
+            byte => Err(TransactionError::InvalidVersion(byte)),
+        }
+    }
+
+    /// Check that every byte was read
+    pub(crate) fn finish(&self) -> Result<(), TransactionError> {
+        if self.offset == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(TransactionError::TrailingBytes(self.offset))
+        }
+    }
+
+    /// Read a public key of 32 bytes
+    pub(crate) fn public_key(&mut self) -> Result<Vec<u8>, TransactionError> {
+        self.bytes(PUBLIC_KEY_LENGTH as usize)
+    }
+
+    /// Read an optional asset id, see `serialize_asset`
+    pub(crate) fn asset(&mut self) -> Result<Option<Vec<u8>>, TransactionError> {
+        match self.bool()? {
+            true => Ok(Some(self.bytes(ASSET_ID_LENGTH as usize)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Read a signature of 64 bytes
+    pub(crate) fn signature(&mut self) -> Result<Vec<u8>, TransactionError> {
+        self.bytes(SIGNATURE_LENGTH as usize)
+    }
+
+    /// Read proofs with a single signature, see `serialize_proofs`
+    pub(crate) fn proofs(&mut self) -> Result<Vec<u8>, TransactionError> {
+        match self.u8()? {
+            PROOFS_VERSION => (),
+            byte => return Err(TransactionError::InvalidVersion(byte)),
+        }
+        match self.u16()? {
+            1 => (),
+            count => return Err(TransactionError::InvalidProofs(count)),
+        }
+        match self.array()? {
+            proof if proof.len() == SIGNATURE_LENGTH as usize => Ok(proof),
+            _ => Err(TransactionError::InvalidSignature),
+        }
+    }
+
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Read the next byte without moving forward
+    pub(crate) fn peek(&self) -> Result<u8, TransactionError> {
+        match self.bytes.get(self.offset) {
+            Some(byte) => Ok(*byte),
+            None => Err(TransactionError::UnexpectedEnd(self.offset)),
+        }
+    }
+}
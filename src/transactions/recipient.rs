@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use crate::network::Network;
+use crate::transactions::alias::Alias;
+use crate::transactions::reader::Reader;
+use crate::transactions::{TransactionError, ALIAS_VERSION};
+use crate::utils::base58::vec_to_b58;
+use crate::wallet::signatures::validate_address;
+use crate::wallet::ADDRESS_LENGTH;
+
+/// Receiver of a transfer, a lease or a mass transfer
+///
+/// - An address like bytes or an alias of the chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recipient {
+    Address(Vec<u8>),
+    Alias(Alias),
+}
+
+impl Recipient {
+    /**
+    # Validate
+    ## Check that the recipient belongs to the chain
+
+    - An address must pass `validate_address`
+    - An alias must be valid and have the same chain id
+    - The network is a `Network` or a chain id
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::Network;
+    use lunesrs::transactions::recipient::Recipient;
+
+    let mainnet = 1;
+    let testnet = 0;
+    let addr: Recipient = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap();
+    let alias: Recipient = "alias:1:lunes".parse().unwrap();
+    let devnet_alias: Recipient = "alias:D:lunes".parse().unwrap();
+
+    assert_eq!(true, addr.validate(mainnet).is_ok());
+    assert_eq!(true, alias.validate(mainnet).is_ok());
+    assert_eq!(true, alias.validate(testnet).is_err());
+    assert_eq!(true, devnet_alias.validate(Network::Custom { byte: b'D' }).is_ok());
+    ```
+    */
+    pub fn validate(&self, network: impl Into<Network>) -> Result<(), TransactionError> {
+        let network = network.into();
+        let valid = match self {
+            Recipient::Address(address) => {
+                address.len() == ADDRESS_LENGTH as usize
+                    && validate_address(network.chain_id(), address.clone())
+            }
+            Recipient::Alias(alias) => {
+                alias.validate()?;
+                alias.network() == network
+            }
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(TransactionError::InvalidRecipient(self.to_string()))
+        }
+    }
+
+    /// Serialize the recipient, the address bytes or `Alias::to_bytes`
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        match self {
+            Recipient::Address(address) => Ok(address.clone()),
+            Recipient::Alias(alias) => alias.to_bytes(),
+        }
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        match reader.peek()? {
+            ALIAS_VERSION => Ok(Recipient::Alias(Alias::read(reader)?)),
+            _ => Ok(Recipient::Address(reader.bytes(ADDRESS_LENGTH as usize)?)),
+        }
+    }
+}
+
+impl std::fmt::Display for Recipient {
+    /// Format an address in base58 and an alias as `alias:<chain>:<name>`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Recipient::Address(address) => write!(f, "{}", vec_to_b58(address.clone())),
+            Recipient::Alias(alias) => write!(f, "{}", alias),
+        }
+    }
+}
+
+impl FromStr for Recipient {
+    type Err = TransactionError;
+
+    /// Parse a base58 address or an alias like `alias:1:lunes` or `alias:D:lunes`
+    ///
+    /// - An address must decode to 26 bytes, its chain is checked by `validate`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TransactionError::InvalidRecipient(s.to_string());
+
+        match s.strip_prefix("alias:") {
+            Some(alias) => match alias.split_once(':') {
+                Some((chain, name)) if chain.len() == 1 => Ok(Recipient::Alias(Alias::new(
+                    chain.parse::<Network>().map_err(|_| invalid())?,
+                    name,
+                ))),
+                _ => Err(invalid()),
+            },
+            None => match bs58::decode(s).into_vec() {
+                Ok(address) if address.len() == ADDRESS_LENGTH as usize => {
+                    Ok(Recipient::Address(address))
+                }
+                _ => Err(invalid()),
+            },
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+
+use crate::transactions::fee::suggest_fee;
+use crate::transactions::reader::Reader;
+use crate::transactions::recipient::Recipient;
+use crate::transactions::{
+    serialize_asset, serialize_field, Transaction, TransactionError, TRANSFER_TYPE,
+};
+use crate::utils::serialize::serialize_integer;
+use crate::wallet::secret::PrivateKey;
+
+/// Transfer of `amount` of an asset from the sender to the recipient
+///
+/// - `asset_id` and `fee_asset` are `None` for LUNES
+/// - `recipient` is an address or an alias
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransferTransaction {
+    #[cfg_attr(feature = "serde", serde(with = "crate::transactions::json::base58"))]
+    pub sender_public_key: Vec<u8>,
+    pub recipient: Recipient,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::transactions::json::base58_option")
+    )]
+    pub asset_id: Option<Vec<u8>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "feeAssetId",
+            alias = "feeAsset",
+            default,
+            with = "crate::transactions::json::base58_option"
+        )
+    )]
+    pub fee_asset: Option<Vec<u8>>,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::transactions::json::base58")
+    )]
+    pub attachment: Vec<u8>,
+}
+
+impl TransferTransaction {
+    /**
+    # Sign
+    ## Sign the transfer with your private key
+
+    - Sign the body bytes with `fast_signature` and return the signed transaction like bytes
+    - Fail with `ArrayTooLong` if the attachment has more than 65535 bytes
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::transfer::TransferTransaction;
+    use lunesrs::transactions::Transaction;
+    use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = TransferTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: None,
+        fee_asset: None,
+        amount: 100000000,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: "Lunes".as_bytes().to_vec(),
+    };
+    let signed = tx.sign(prvk).unwrap();
+
+    assert_eq!(true, validate_signature(pubk, tx.body_bytes().unwrap(), signed[1..65].to_vec()));
+    ```
+    */
+    pub fn sign(&self, private_key: impl Into<PrivateKey>) -> Result<Vec<u8>, TransactionError> {
+        let body_bytes = self.body_bytes()?;
+        self.signed_bytes(private_key.into().sign(body_bytes))
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, TransactionError> {
+        reader.expect_type(TRANSFER_TYPE)?;
+
+        Ok(TransferTransaction {
+            sender_public_key: reader.public_key()?,
+            asset_id: reader.asset()?,
+            fee_asset: reader.asset()?,
+            timestamp: reader.u64()?,
+            amount: reader.u64()?,
+            fee: reader.u64()?,
+            recipient: Recipient::read(reader)?,
+            attachment: reader.array()?,
+        })
+    }
+}
+
+impl Transaction for TransferTransaction {
+    /**
+    # Body Bytes
+    ## Serialize the transfer in the Lunes binary layout
+
+    - `type | sender public key | asset | fee asset | timestamp | amount | fee | recipient | attachment`
+    - These are the bytes that get signed
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::transfer::TransferTransaction;
+    use lunesrs::transactions::Transaction;
+
+    let tx = TransferTransaction {
+        sender_public_key: vec![1; 32],
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: None,
+        fee_asset: None,
+        amount: 100000000,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    };
+
+    assert_eq!(tx.body_bytes().unwrap().len(), 1 + 32 + 1 + 1 + 8 + 8 + 8 + 26 + 2);
+    ```
+    */
+    fn body_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok([
+            vec![TRANSFER_TYPE],
+            self.sender_public_key.clone(),
+            serialize_asset(&self.asset_id),
+            serialize_asset(&self.fee_asset),
+            serialize_integer(self.timestamp),
+            serialize_integer(self.amount),
+            serialize_integer(self.fee),
+            self.recipient.to_bytes()?,
+            serialize_field(self.attachment.clone())?,
+        ]
+        .concat())
+    }
+
+    /// Assemble the signed transaction as `type | signature | body bytes`
+    fn signed_bytes(&self, signature: Vec<u8>) -> Result<Vec<u8>, TransactionError> {
+        Ok([vec![TRANSFER_TYPE], signature, self.body_bytes()?].concat())
+    }
+}
+
+/**
+# Sign Transfer
+## Build, serialize and sign a transfer transaction
+
+- Receive your private key and the transfer fields and return the signed transaction like bytes
+- The recipient is a base58 address or an alias like `alias:1:lunes`
+- An empty `assetId` or `feeAsset` means LUNES
+- Throw if the recipient can not be parsed or the attachment has more than 65535 bytes
+- A `fee` of `0` is replaced by the suggested fee, see `suggest_fee`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const privateKey: Uint8Array = wasm.toPrivateKey(new Uint8Array(32))
+const publicKey: Uint8Array = wasm.toPublicKey(privateKey)
+const recipient = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj"
+
+const signed: Uint8Array = wasm.signTransfer(
+    privateKey, publicKey, recipient, new Uint8Array(), new Uint8Array(),
+    100000000n, 1000000n, BigInt(Date.now()), wasm.serializeString("Lunes")
+)
+```
+*/
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "signTransfer")]
+pub fn sign_transfer(
+    private_key: Vec<u8>,
+    sender_public_key: Vec<u8>,
+    recipient: String,
+    asset_id: Vec<u8>,
+    fee_asset: Vec<u8>,
+    amount: u64,
+    fee: u64,
+    timestamp: u64,
+    attachment: Vec<u8>,
+) -> Result<Vec<u8>, LunesError> {
+    let optional = |id: Vec<u8>| if id.is_empty() { None } else { Some(id) };
+
+    let mut tx = TransferTransaction {
+        sender_public_key,
+        recipient: recipient.parse::<Recipient>()?,
+        asset_id: optional(asset_id),
+        fee_asset: optional(fee_asset),
+        amount,
+        fee,
+        timestamp,
+        attachment,
+    };
+    if tx.fee == 0 {
+        tx.fee = suggest_fee(&tx);
+    }
+
+    tx.sign(private_key).map_err(LunesError::from)
+}
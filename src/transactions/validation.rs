@@ -0,0 +1,321 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::transactions::alias::CreateAliasTransaction;
+use crate::transactions::burn::BurnTransaction;
+use crate::transactions::data::DataTransaction;
+use crate::transactions::issue::IssueTransaction;
+use crate::transactions::lease::{LeaseCancelTransaction, LeaseTransaction};
+use crate::transactions::mass_transfer::MassTransferTransaction;
+use crate::transactions::parser::{parse_transaction, TransactionKind};
+use crate::transactions::recipient::Recipient;
+use crate::transactions::reissue::ReissueTransaction;
+use crate::transactions::transfer::TransferTransaction;
+use crate::transactions::{
+    TransactionError, MAX_AMOUNT, MAX_ATTACHMENT_LENGTH, MAX_TIMESTAMP_FUTURE, MAX_TIMESTAMP_PAST,
+};
+use crate::wallet::assembly::to_address;
+use crate::wallet::ADDRESS_VERSION;
+
+/// Network and clock a transaction is checked against
+///
+/// - `now` is the current time in milliseconds, like `Date.now()`
+/// - `max_past` and `max_future` are the window around `now` the node accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub network: Network,
+    pub now: u64,
+    pub max_past: u64,
+    pub max_future: u64,
+}
+
+impl Rules {
+    /// Rules of the node of the network, with the default timestamp window
+    pub fn new(network: impl Into<Network>, now: u64) -> Rules {
+        Rules {
+            network: network.into(),
+            now,
+            max_past: MAX_TIMESTAMP_PAST,
+            max_future: MAX_TIMESTAMP_FUTURE,
+        }
+    }
+
+    fn timestamp(&self, timestamp: u64) -> Option<TransactionError> {
+        let too_old = timestamp.saturating_add(self.max_past) < self.now;
+        let too_new = timestamp > self.now.saturating_add(self.max_future);
+
+        (too_old || too_new).then_some(TransactionError::InvalidTimestamp(timestamp))
+    }
+
+    /// Timestamp and fee, the rules of every transaction
+    fn common(&self, timestamp: u64, fee: u64) -> Vec<TransactionError> {
+        let fee = match fee {
+            0 => Some(TransactionError::ZeroFee),
+            fee if fee > MAX_AMOUNT => Some(TransactionError::FeeOverflow),
+            _ => None,
+        };
+
+        self.timestamp(timestamp).into_iter().chain(fee).collect()
+    }
+}
+
+/// Every rule a transaction breaks, returned by `Validate::validate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violations(pub Vec<TransactionError>);
+
+impl Violations {
+    pub fn contains(&self, error: &TransactionError) -> bool {
+        self.0.contains(error)
+    }
+}
+
+impl std::fmt::Display for Violations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let errors = self.0.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        write!(f, "invalid transaction: {}", errors.join(", "))
+    }
+}
+
+impl std::error::Error for Violations {}
+
+/**
+# Validate
+## Catch what the node would reject before signing or broadcasting
+
+- `validate` is the check of every transaction, it fails with every violation, `violations` lists them
+- Every transaction checks its timestamp against `Rules` and a fee between 1 and `MAX_AMOUNT`
+- Transfers check the amount, the attachment and that the recipient is of the network
+- Leases also refuse the address of the sender as recipient
+- Issues check the name, description and decimals, mass transfers and data their entries
+
+## In Rust 🤝
+
+```rust
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::validation::{Rules, Validate};
+use lunesrs::transactions::TransactionError;
+
+let mainnet = 1;
+let tx = TransferTransaction {
+    sender_public_key: vec![1; 32],
+    recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+    asset_id: None,
+    fee_asset: None,
+    amount: 0,
+    fee: 0,
+    timestamp: 1528077600000,
+    attachment: vec![],
+};
+
+assert_eq!(
+    tx.violations(&Rules::new(mainnet, 1528077600000)),
+    [TransactionError::ZeroFee, TransactionError::ZeroAmount]
+);
+assert_eq!(true, tx.validate(&Rules::new(mainnet, 1528077600000)).is_err());
+```
+*/
+pub trait Validate {
+    /// Every rule the transaction breaks, empty if the node would accept it
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError>;
+
+    /// `Ok` if the node would accept the transaction, else every violation
+    fn validate(&self, rules: &Rules) -> Result<(), Violations> {
+        match self.violations(rules) {
+            errors if errors.is_empty() => Ok(()),
+            errors => Err(Violations(errors)),
+        }
+    }
+}
+
+/// `Ok` if there are no errors, else the first one
+pub(crate) fn first_error(errors: Vec<TransactionError>) -> Result<(), TransactionError> {
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+fn amount(amount: u64) -> Option<TransactionError> {
+    match amount {
+        0 => Some(TransactionError::ZeroAmount),
+        amount => quantity(amount),
+    }
+}
+
+/// Amounts that can be `0`, like the amount of a burn, the node refuses an issue or reissue of `0`
+fn quantity(quantity: u64) -> Option<TransactionError> {
+    (quantity > MAX_AMOUNT).then_some(TransactionError::AmountOverflow)
+}
+
+fn attachment(attachment: &[u8]) -> Option<TransactionError> {
+    (attachment.len() > MAX_ATTACHMENT_LENGTH)
+        .then_some(TransactionError::AttachmentTooLong(attachment.len()))
+}
+
+impl Validate for TransferTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(amount(self.amount));
+        errors.extend(attachment(&self.attachment));
+        errors.extend(self.recipient.validate(rules.network).err());
+        errors
+    }
+}
+
+impl Validate for IssueTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(amount(self.quantity));
+        errors.extend(self.errors());
+        errors
+    }
+}
+
+impl Validate for ReissueTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(amount(self.quantity));
+        errors.extend(self.errors());
+        errors
+    }
+}
+
+impl Validate for BurnTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(quantity(self.amount));
+        errors.extend(self.errors());
+        errors
+    }
+}
+
+impl Validate for LeaseTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let sender = to_address(
+            ADDRESS_VERSION[0],
+            rules.network.chain_id(),
+            self.sender_public_key.clone(),
+        );
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(amount(self.amount));
+        errors.extend(self.recipient.validate(rules.network).err());
+        if self.recipient == Recipient::Address(sender) {
+            errors.push(TransactionError::LeaseToSelf);
+        }
+        errors
+    }
+}
+
+impl Validate for LeaseCancelTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        rules.common(self.timestamp, self.fee)
+    }
+}
+
+impl Validate for CreateAliasTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(self.alias.validate().err());
+        errors
+    }
+}
+
+impl Validate for MassTransferTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let total = self
+            .transfers
+            .iter()
+            .try_fold(0u64, |total, transfer| total.checked_add(transfer.amount));
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(total.map_or(Some(TransactionError::AmountOverflow), quantity));
+        errors.extend(attachment(&self.attachment));
+        errors.extend(self.errors(rules.network));
+        errors
+    }
+}
+
+impl Validate for DataTransaction {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        let mut errors = rules.common(self.timestamp, self.fee);
+        errors.extend(self.errors());
+        errors
+    }
+}
+
+impl Validate for TransactionKind {
+    fn violations(&self, rules: &Rules) -> Vec<TransactionError> {
+        match self {
+            TransactionKind::Issue(tx) => tx.violations(rules),
+            TransactionKind::Transfer(tx) => tx.violations(rules),
+            TransactionKind::Reissue(tx) => tx.violations(rules),
+            TransactionKind::Burn(tx) => tx.violations(rules),
+            TransactionKind::Lease(tx) => tx.violations(rules),
+            TransactionKind::LeaseCancel(tx) => tx.violations(rules),
+            TransactionKind::CreateAlias(tx) => tx.violations(rules),
+            TransactionKind::MassTransfer(tx) => tx.violations(rules),
+            TransactionKind::Data(tx) => tx.violations(rules),
+        }
+    }
+}
+
+/**
+# Validate Transaction
+## Check signed bytes against the rules of the node before broadcasting them
+
+- `now` is the current time in milliseconds
+- Fail if the bytes are not a signed transaction, or with every violation, see `Validate`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::transactions::lease::sign_lease;
+use lunesrs::transactions::validation::validate_transaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+
+let private_key = to_private_key(vec![1; 32]);
+let public_key = to_public_key(private_key.clone());
+let node = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string();
+let signed = sign_lease(private_key, Network::Mainnet, public_key, node, 100000000, 0, 1528077600000).unwrap();
+
+assert_eq!(validate_transaction(signed.clone(), Network::Mainnet, 1528077600000), Ok(()));
+assert_eq!(true, validate_transaction(signed, Network::Testnet, 1528077600000).is_err());
+```
+*/
+pub fn validate_transaction(
+    signed_bytes: Vec<u8>,
+    network: impl Into<Network>,
+    now: u64,
+) -> Result<(), LunesError> {
+    parse_transaction(signed_bytes)?
+        .transaction
+        .validate(&Rules::new(network, now))
+        .map_err(LunesError::from)
+}
+
+/**
+# Validate Transaction
+## `validate_transaction` for JavaScript, with the chain id of the network
+
+- `chainId` is the chain id of `Network::chain_id`, take it from `chainIdOf("mainnet")`
+- Throw if the bytes are not a signed transaction, or with every violation, see `Validate`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const signed: Uint8Array = wasm.signTransfer(...)
+
+wasm.validateTransaction(signed, wasm.chainIdOf("mainnet"), BigInt(Date.now()))
+```
+*/
+#[wasm_bindgen(js_name = "validateTransaction")]
+pub fn validate_transaction_js(
+    signed_bytes: Vec<u8>,
+    chain_id: u8,
+    now: u64,
+) -> Result<(), LunesError> {
+    validate_transaction(signed_bytes, Network::from(chain_id), now)
+}
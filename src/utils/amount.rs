@@ -0,0 +1,215 @@
+use std::fmt;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::transactions::{MAX_AMOUNT, MAX_ASSET_DECIMALS};
+use crate::utils::serialize::serialize_integer;
+
+/// Decimals of LUNES, 1 LUNES = 100000000 units
+pub const LUNES_DECIMALS: u8 = 8;
+
+/**
+# Amount
+## Raw units of LUNES or a token with the decimals of the asset
+
+- `units` is the integer the transactions carry, `decimals` only changes how it is read
+- `parse` and `toString` convert exactly between `"1.5"` and `150000000`, without floats
+- Amounts are at most `MAX_AMOUNT`, `new` and `lunes` fail with `AmountOverflow` above it
+- Arithmetic is checked, it fails above `MAX_AMOUNT`, below zero or on different decimals
+- `toBytes` are the big-endian bytes of the units, like `serialize_integer`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const amount = wasm.Amount.parse("1.5", 8)
+
+amount.units === 150000000n
+amount.add(wasm.Amount.lunes(50000000n)).toString() === "2"
+amount.toFixed() === "1.50000000"
+wasm.Amount.parse("0.000000001", 8) // throws Error
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::utils::amount::Amount;
+
+let amount = Amount::parse("1.5".to_string(), 8).unwrap();
+let fee = Amount::lunes(100000).unwrap();
+
+assert_eq!(amount.units(), 150000000);
+assert_eq!(amount.to_string(), "1.5");
+assert_eq!(amount.checked_add(&fee).unwrap().to_string(), "1.501");
+assert_eq!(fee.to_fixed(), "0.00100000");
+assert_eq!(amount.to_bytes(), [0, 0, 0, 0, 8, 240, 209, 128]);
+assert_eq!(true, fee.checked_sub(&amount).is_err());
+```
+*/
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Amount {
+    units: u64,
+    decimals: u8,
+}
+
+#[wasm_bindgen]
+impl Amount {
+    /// Amount of raw units, fail if `decimals` is more than 8 or `units` is over `MAX_AMOUNT`
+    #[wasm_bindgen(constructor)]
+    pub fn new(units: u64, decimals: u8) -> Result<Amount, LunesError> {
+        if decimals > MAX_ASSET_DECIMALS {
+            return Err(LunesError::InvalidAmount(format!(
+                "decimals must be at most {}, got {}",
+                MAX_ASSET_DECIMALS, decimals
+            )));
+        }
+
+        Amount { units: 0, decimals }.with_units(Some(units))
+    }
+
+    /// Amount of LUNES in raw units, fail if `units` is over `MAX_AMOUNT`
+    pub fn lunes(units: u64) -> Result<Amount, LunesError> {
+        Amount::new(units, LUNES_DECIMALS)
+    }
+
+    /**
+    # Parse
+    ## Read a decimal number like `"1.5"` into raw units
+
+    - Only digits with an optional `.` and at most `decimals` digits after it
+    - Fail with `InvalidAmount` on signs, exponents, spaces or extra decimal places
+    - Fail with `AmountOverflow` if the units are more than `MAX_AMOUNT`
+    */
+    pub fn parse(text: String, decimals: u8) -> Result<Amount, LunesError> {
+        let invalid = |reason: &str| LunesError::InvalidAmount(format!("{:?} {}", text, reason));
+        let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+        if integer.is_empty() || text.ends_with('.') {
+            return Err(invalid("must have digits before and after the point"));
+        }
+        if !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("must have only digits and a point"));
+        }
+        if fraction.len() > decimals as usize {
+            return Err(invalid(&format!(
+                "has more than {} decimal places",
+                decimals
+            )));
+        }
+
+        let scale = Amount::new(0, decimals)?.scale();
+        let padded = format!("{:0<width$}", fraction, width = decimals as usize);
+        let units = integer
+            .parse::<u64>()
+            .ok()
+            .and_then(|integer| integer.checked_mul(scale))
+            .and_then(|units| units.checked_add(padded.parse().unwrap_or(0)));
+
+        Amount { units: 0, decimals }.with_units(units)
+    }
+
+    /// Raw units, like the `amount` and `fee` of the transactions
+    #[wasm_bindgen(getter)]
+    pub fn units(&self) -> u64 {
+        self.units
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Sum of two amounts with the same decimals
+    #[wasm_bindgen(js_name = "add")]
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, LunesError> {
+        self.same_decimals(other)?;
+        self.with_units(self.units.checked_add(other.units))
+    }
+
+    /// Difference of two amounts with the same decimals, fail if `other` is greater
+    #[wasm_bindgen(js_name = "sub")]
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, LunesError> {
+        self.same_decimals(other)?;
+        self.with_units(self.units.checked_sub(other.units))
+    }
+
+    /// Amount times an integer, like the fee of many transfers
+    #[wasm_bindgen(js_name = "mul")]
+    pub fn checked_mul(&self, times: u64) -> Result<Amount, LunesError> {
+        self.with_units(self.units.checked_mul(times))
+    }
+
+    /// Decimal number with every decimal place, `"1.50000000"`
+    #[wasm_bindgen(js_name = "toFixed")]
+    pub fn to_fixed(&self) -> String {
+        match self.decimals {
+            0 => self.units.to_string(),
+            decimals => format!(
+                "{}.{:0width$}",
+                self.units / self.scale(),
+                self.units % self.scale(),
+                width = decimals as usize
+            ),
+        }
+    }
+
+    /// Decimal number without trailing zeros, `"1.5"`
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Big-endian bytes of the units, see `serialize_integer`
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_integer(self.units)
+    }
+}
+
+impl Amount {
+    /// `10 ^ decimals`, units of 1 whole LUNES or token
+    fn scale(&self) -> u64 {
+        10u64.pow(self.decimals as u32)
+    }
+
+    fn same_decimals(&self, other: &Amount) -> Result<(), LunesError> {
+        match self.decimals == other.decimals {
+            true => Ok(()),
+            false => Err(LunesError::MismatchedDecimals(
+                self.decimals,
+                other.decimals,
+            )),
+        }
+    }
+
+    /// Amount with the same decimals, fail if the units overflowed or are over `MAX_AMOUNT`
+    fn with_units(&self, units: Option<u64>) -> Result<Amount, LunesError> {
+        units
+            .filter(|units| *units <= MAX_AMOUNT)
+            .map(|units| Amount { units, ..*self })
+            .ok_or(LunesError::AmountOverflow)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fixed = self.to_fixed();
+        match self.decimals {
+            0 => write!(f, "{}", fixed),
+            _ => write!(f, "{}", fixed.trim_end_matches('0').trim_end_matches('.')),
+        }
+    }
+}
+
+impl From<Amount> for u64 {
+    fn from(amount: Amount) -> u64 {
+        amount.units
+    }
+}
@@ -1,5 +1,7 @@
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use crate::error::LunesError;
+
 /**
 # Encoding your message with *`base58`*
 
@@ -36,6 +38,31 @@ pub fn vec_to_b58(vec: Vec<u8>) -> String {
 # Dencoding your message with *`base58`*
 
 - Receive a message in bytes(Vec u8) and return string base58 decoded
+- Panic if the string is not base58, use `try_b58_to_vec` for user input
+
+## In Rust 🤝
+
+```rust
+use lunesrs::utils::base58::b58_to_vec;
+
+let input = "DEiWH5L".to_string();
+let output = b58_to_vec(input);
+
+assert_eq!(output, [108, 117, 110, 101, 115]);
+```
+*/
+pub fn b58_to_vec(message: String) -> Vec<u8> {
+    match try_b58_to_vec(message) {
+        Ok(arr) => arr,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/**
+# Try decoding your message with *`base58`*
+
+- Receive a string base58 encoded and return the message in bytes(Vec u8)
+- Return `LunesError::InvalidBase58` instead of a panic, throw an `Error` in JavaScript
 
 ## In JavaScript 👍
 
@@ -44,25 +71,24 @@ import * as wasm from "lunesrs"
 
 const result: Uint8Array = wasm.base58ToArray("DEiWH5L")
 result === [108, 117, 110, 101, 115]
+
+wasm.base58ToArray("0OIl") // throws Error
 ```
 
 ## In Rust 🤝
 
 ```rust
-use lunesrs::utils::base58::b58_to_vec;
+use lunesrs::utils::base58::try_b58_to_vec;
 
-let input = "DEiWH5L".to_string();
-let output = b58_to_vec(input);
-
-assert_eq!(output, [108, 117, 110, 101, 115]);
+assert_eq!(try_b58_to_vec("DEiWH5L".to_string()), Ok(vec![108, 117, 110, 101, 115]));
+assert_eq!(true, try_b58_to_vec("0OIl".to_string()).is_err());
 ```
 */
 #[wasm_bindgen(js_name = "base58ToArray")]
-pub fn b58_to_vec(message: String) -> Vec<u8> {
+pub fn try_b58_to_vec(message: String) -> Result<Vec<u8>, LunesError> {
     use bs58::decode;
 
-    match decode(message).into_vec() {
-        Ok(arr) => arr,
-        Err(e) => panic!("{}", e),
-    }
+    decode(message)
+        .into_vec()
+        .map_err(|e| LunesError::InvalidBase58(e.to_string()))
 }
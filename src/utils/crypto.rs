@@ -1,3 +1,5 @@
+use crate::error::LunesError;
+
 /**
 # Hash your message with *`sha2 256`*
 
@@ -10,10 +12,15 @@ use lunesrs::utils::crypto::to_sha256;
 ```
 */
 pub fn to_sha256(message: Vec<u8>) -> Vec<u8> {
+    sha256(&message)
+}
+
+/// `to_sha256` of borrowed bytes, for secrets that must not be copied
+pub(crate) fn sha256(message: &[u8]) -> Vec<u8> {
     use sha2::{Digest, Sha256};
 
     let mut hasher = Sha256::new();
-    hasher.update(&message);
+    hasher.update(message);
 
     hasher.finalize().to_vec()
 }
@@ -39,12 +46,17 @@ assert_eq!(output, response);
 ```
 */
 pub fn to_keccak256(message: Vec<u8>) -> Vec<u8> {
+    keccak256(&message)
+}
+
+/// `to_keccak256` of borrowed bytes, for secrets that must not be copied
+pub(crate) fn keccak256(message: &[u8]) -> Vec<u8> {
     use tiny_keccak::{Hasher, Keccak};
 
     let mut k256 = Keccak::v256();
     let mut result = [0; 32];
 
-    k256.update(&message);
+    k256.update(message);
     k256.finalize(&mut result);
 
     result.to_vec()
@@ -71,16 +83,40 @@ assert_eq!(output, response);
 ```
 */
 pub fn to_blake2b32b(message: Vec<u8>) -> Vec<u8> {
+    match try_to_blake2b32b(message) {
+        Ok(hash) => hash,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/**
+# Try hashing your message with *`blake 2b 32bytes`*
+
+- Receive a message in bytes(Vec u8) and return a hash in bytes(Vec u8)
+- Return `LunesError::Hash` instead of a panic if the hasher can not be built
+
+## Example
+
+```rust
+use lunesrs::utils::crypto::{to_blake2b32b, try_to_blake2b32b};
+
+let input = "lunes".to_string().as_bytes().to_vec();
+
+assert_eq!(try_to_blake2b32b(input.clone()), Ok(to_blake2b32b(input)));
+```
+*/
+pub fn try_to_blake2b32b(message: Vec<u8>) -> Result<Vec<u8>, LunesError> {
+    blake2b32b(&message)
+}
+
+/// `try_to_blake2b32b` of borrowed bytes, for secrets that must not be copied
+pub(crate) fn blake2b32b(message: &[u8]) -> Result<Vec<u8>, LunesError> {
     use blake2::{
         digest::{Update, VariableOutput},
         Blake2bVar,
     };
 
-    match Blake2bVar::new(32) {
-        Ok(mut hash) => {
-            hash.update(&message);
-            hash.finalize_boxed().to_vec()
-        }
-        Err(e) => panic!("{}", e),
-    }
+    let mut hash = Blake2bVar::new(32).map_err(|e| LunesError::Hash(e.to_string()))?;
+    hash.update(message);
+    Ok(hash.finalize_boxed().into_vec())
 }
@@ -1,3 +1,5 @@
+/// Fixed-point amounts of LUNES and tokens
+pub mod amount;
 /// Functions for encode and decode with base58
 pub mod base58;
 /// Functions low leval of cryptography
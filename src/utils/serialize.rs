@@ -1,5 +1,10 @@
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use crate::error::LunesError;
+
+/// Longest array `serialize_array` can prefix with a u16 length
+pub const MAX_ARRAY_LENGTH: usize = u16::MAX as usize;
+
 /**
 # Serialize Unsigned Integer
 
@@ -57,9 +62,46 @@ assert_eq!(
 */
 #[wasm_bindgen(js_name = "serializeString")]
 pub fn serialize_string(token: String) -> Vec<u8> {
-    if token == "" {
+    if token.is_empty() {
         0u8.to_be_bytes().to_vec()
     } else {
         token.as_bytes().to_vec()
     }
 }
+
+/**
+# Serialize Arrays
+
+- The function prefix an array of bytes with its length as a big-endian u16
+- Used for variable-length fields like attachments, names and descriptions
+- Throw if the array has more than 65535 bytes, the length would not fit in the prefix
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const result: Uint8Array = wasm.serializeArray(new Uint8Array([108, 117, 110, 101, 115]))
+result === [0, 5, 108, 117, 110, 101, 115]
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::utils::serialize::serialize_array;
+
+assert_eq!(serialize_array(vec![]), Ok(vec![0, 0]));
+assert_eq!(
+    serialize_array("lunes".as_bytes().to_vec()),
+    Ok(vec![0, 5, 108, 117, 110, 101, 115])
+);
+assert_eq!(true, serialize_array(vec![0; 65536]).is_err());
+```
+*/
+#[wasm_bindgen(js_name = "serializeArray")]
+pub fn serialize_array(array: Vec<u8>) -> Result<Vec<u8>, LunesError> {
+    match u16::try_from(array.len()) {
+        Ok(len) => Ok([len.to_be_bytes().to_vec(), array].concat()),
+        Err(_) => Err(LunesError::ArrayTooLong(array.len())),
+    }
+}
@@ -0,0 +1,254 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::transactions::Transaction;
+use crate::utils::base58::vec_to_b58;
+use crate::wallet::assembly::to_address;
+use crate::wallet::secret::{PrivateKey, Seed};
+use crate::wallet::signatures::validate_signature;
+use crate::wallet::{ADDRESS_VERSION, PRIVATE_KEY_LENGTH, PUBLIC_KEY_LENGTH};
+
+/**
+# Account
+## Seed, nonce, keys and address of a Lunes account
+
+- `from_seed` derives the keys like lunesjs: `hidden_seed` → `to_private_key` → `to_public_key` → `to_address`
+- The seed and the private key are kept in `Seed` and `PrivateKey`, so `Debug` does not print them
+- `from_private_key` forgets the seed, `from_public_key` is watch-only and can not sign
+- Keys and address are available like bytes and encoded in base58
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const mainnet = 1
+const account = wasm.Account.fromSeed("scrub guard swim ...", 0, mainnet)
+
+account.addressB58 === "37..."
+const signature: Uint8Array = account.signMessage(wasm.serializeString("Lunes"))
+account.verifyMessage(wasm.serializeString("Lunes"), signature) === true
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::account::Account;
+
+let mainnet = 1;
+let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let account = Account::from_seed(seed.to_string(), 0, mainnet);
+let watch = Account::from_public_key(account.public_key(), mainnet).unwrap();
+let msg = "Lunes".as_bytes().to_vec();
+let signature = account.sign_message(msg.clone()).unwrap();
+
+assert_eq!(account.address_b58(), watch.address_b58());
+assert_eq!(true, watch.verify_message(msg.clone(), signature));
+assert_eq!(true, watch.sign_message(msg).is_err());
+```
+*/
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Account {
+    seed: Option<Seed>,
+    nonce: Option<u32>,
+    private_key: Option<PrivateKey>,
+    public_key: Vec<u8>,
+    address: Vec<u8>,
+    network: Network,
+}
+
+#[wasm_bindgen]
+impl Account {
+    /// Derive the account of the `nonce` of a seed phrase, see `hidden_seed`
+    #[wasm_bindgen(js_name = "fromSeed")]
+    pub fn from_seed(seed: String, nonce: u32, chain_id: u8) -> Account {
+        Account::from_secret_seed(Seed::from(seed), nonce, chain_id)
+    }
+
+    /// Account of a private key, without seed and nonce
+    #[wasm_bindgen(js_name = "fromPrivateKey")]
+    pub fn from_private_key(private_key: Vec<u8>, chain_id: u8) -> Result<Account, LunesError> {
+        Account::from_secret_key(PrivateKey::from(private_key), chain_id)
+    }
+
+    /// Watch-only account of a public key, it can verify but not sign
+    #[wasm_bindgen(js_name = "fromPublicKey")]
+    pub fn from_public_key(public_key: Vec<u8>, chain_id: u8) -> Result<Account, LunesError> {
+        if public_key.len() != PUBLIC_KEY_LENGTH as usize {
+            return Err(LunesError::InvalidKeyLength(public_key.len()));
+        }
+
+        Ok(Account {
+            seed: None,
+            nonce: None,
+            private_key: None,
+            address: to_address(ADDRESS_VERSION[0], chain_id, public_key.clone()),
+            public_key,
+            network: Network::from(chain_id),
+        })
+    }
+
+    /// Seed phrase, only for accounts built with `from_seed`
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Option<String> {
+        self.seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().to_string())
+    }
+
+    /// Nonce of the seed phrase, only for accounts built with `from_seed`
+    #[wasm_bindgen(getter)]
+    pub fn nonce(&self) -> Option<u32> {
+        self.nonce
+    }
+
+    /// Chain id given to `to_address`, see `Network::chain_id`
+    #[wasm_bindgen(getter, js_name = "chainId")]
+    pub fn chain_id(&self) -> u8 {
+        self.network.chain_id()
+    }
+
+    /// Private key like bytes, `None` for watch-only accounts
+    #[wasm_bindgen(getter, js_name = "privateKey")]
+    pub fn private_key(&self) -> Option<Vec<u8>> {
+        self.private_key
+            .as_ref()
+            .map(|key| key.expose_secret().to_vec())
+    }
+
+    /// Private key encoded in base58, `None` for watch-only accounts
+    #[wasm_bindgen(getter, js_name = "privateKeyB58")]
+    pub fn private_key_b58(&self) -> Option<String> {
+        self.private_key().map(vec_to_b58)
+    }
+
+    #[wasm_bindgen(getter, js_name = "publicKey")]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "publicKeyB58")]
+    pub fn public_key_b58(&self) -> String {
+        vec_to_b58(self.public_key.clone())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> Vec<u8> {
+        self.address.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = "addressB58")]
+    pub fn address_b58(&self) -> String {
+        vec_to_b58(self.address.clone())
+    }
+
+    /// `true` if the account has no private key
+    #[wasm_bindgen(getter, js_name = "isWatchOnly")]
+    pub fn is_watch_only(&self) -> bool {
+        self.private_key.is_none()
+    }
+
+    /// Sign a message with `fast_signature`, fail for watch-only accounts
+    #[wasm_bindgen(js_name = "signMessage")]
+    pub fn sign_message(&self, message: Vec<u8>) -> Result<Vec<u8>, LunesError> {
+        match &self.private_key {
+            Some(private_key) => Ok(private_key.sign(message)),
+            None => Err(LunesError::WatchOnly),
+        }
+    }
+
+    /// Check a signature of the message with `validate_signature`
+    #[wasm_bindgen(js_name = "verifyMessage")]
+    pub fn verify_message(&self, message: Vec<u8>, signature: Vec<u8>) -> bool {
+        validate_signature(self.public_key.clone(), message, signature)
+    }
+}
+
+impl Account {
+    /// Derive the account of the `nonce` of a `Seed`, see `from_seed`
+    pub fn from_secret_seed(seed: Seed, nonce: u32, network: impl Into<Network>) -> Account {
+        let private_key = seed.private_key(nonce);
+
+        Account {
+            seed: Some(seed),
+            nonce: Some(nonce),
+            ..Account::with_private_key(private_key, network.into())
+        }
+    }
+
+    /// Account of a `PrivateKey`, see `from_private_key`
+    pub fn from_secret_key(
+        private_key: PrivateKey,
+        network: impl Into<Network>,
+    ) -> Result<Account, LunesError> {
+        let length = private_key.expose_secret().len();
+        if length != PRIVATE_KEY_LENGTH as usize {
+            return Err(LunesError::InvalidKeyLength(length));
+        }
+
+        Ok(Account::with_private_key(private_key, network.into()))
+    }
+
+    /// Network of the address
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Seed phrase of the account, `None` if it was not built from a seed
+    pub fn secret_seed(&self) -> Option<&Seed> {
+        self.seed.as_ref()
+    }
+
+    /// Private key of the account, `None` for watch-only accounts
+    pub fn secret_key(&self) -> Option<&PrivateKey> {
+        self.private_key.as_ref()
+    }
+
+    fn with_private_key(private_key: PrivateKey, network: Network) -> Account {
+        let public_key = private_key.public_key();
+
+        Account {
+            seed: None,
+            nonce: None,
+            private_key: Some(private_key),
+            address: to_address(ADDRESS_VERSION[0], network.chain_id(), public_key.clone()),
+            public_key,
+            network,
+        }
+    }
+
+    /**
+    # Sign Transaction
+    ## Sign any transaction with the private key of the account
+
+    - Return the signed transaction like bytes
+    - Fail for watch-only accounts or if the transaction can not be serialized
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::transactions::burn::BurnTransaction;
+    use lunesrs::transactions::parser::parse_transaction;
+    use lunesrs::wallet::account::Account;
+
+    let account = Account::from_private_key(vec![1; 32], 1).unwrap();
+    let tx = BurnTransaction {
+        sender_public_key: account.public_key(),
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(true, parse_transaction(account.sign_transaction(&tx).unwrap()).is_ok());
+    ```
+    */
+    pub fn sign_transaction<T: Transaction>(&self, transaction: &T) -> Result<Vec<u8>, LunesError> {
+        let signature = self.sign_message(transaction.body_bytes()?)?;
+        transaction
+            .signed_bytes(signature)
+            .map_err(LunesError::from)
+    }
+}
@@ -0,0 +1,194 @@
+use std::str::FromStr;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::utils::base58::{try_b58_to_vec, vec_to_b58};
+use crate::wallet::assembly::to_address;
+use crate::wallet::signatures::check_address;
+use crate::wallet::{
+    ADDRESS_CHECKSUM_LENGTH, ADDRESS_HASH_LENGTH, ADDRESS_LENGTH, ADDRESS_VERSION,
+    PUBLIC_KEY_LENGTH,
+};
+
+/**
+# Address
+## Lunes address checked when it is built
+
+- `version | chain byte | public key hash | checksum`, 26 bytes
+- Parse it from base58 with `str::parse` or `from_b58`, format it with `Display`
+- Parsing checks the length, the version and the checksum, not the chain, see `check_chain`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const address = wasm.Address.fromB58("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj")
+
+address.chainId === 1
+address.checkChain(0) // throws Error
+address.toString() === "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj"
+wasm.Address.fromB58("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aak") // throws Error
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::network::Network;
+use lunesrs::wallet::address::Address;
+
+let address: Address = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap();
+
+assert_eq!(address.version(), 1);
+assert_eq!(address.chain_byte(), b'1');
+assert_eq!(address.chain_id(), 1);
+assert_eq!(address.network(), Network::Mainnet);
+assert_eq!(address.public_key_hash().len(), 20);
+assert_eq!(address.to_string(), "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+assert_eq!(true, "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aak".parse::<Address>().is_err());
+```
+*/
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Address {
+    bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl Address {
+    /// Check the length, the version and the checksum of an address like bytes
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Address, LunesError> {
+        check_address(&bytes, None)?;
+
+        Ok(Address { bytes })
+    }
+
+    /// Decode and check an address encoded in base58, see `from_bytes`
+    #[wasm_bindgen(js_name = "fromB58")]
+    pub fn from_b58(address: String) -> Result<Address, LunesError> {
+        Address::from_bytes(try_b58_to_vec(address)?)
+    }
+
+    /// Address of a public key in a chain, see `to_address`
+    #[wasm_bindgen(js_name = "fromPublicKey")]
+    pub fn from_public_key(public_key: Vec<u8>, chain_id: u8) -> Result<Address, LunesError> {
+        Address::new(public_key, chain_id)
+    }
+
+    /// Version byte, `1` or `11`
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Chain byte like stored in the address, `b'1'` in the mainnet
+    #[wasm_bindgen(getter, js_name = "chainByte")]
+    pub fn chain_byte(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// Chain id of the chain byte, see `Network::chain_id`
+    #[wasm_bindgen(getter, js_name = "chainId")]
+    pub fn chain_id(&self) -> u8 {
+        self.network().chain_id()
+    }
+
+    /// First 20 bytes of `keccak256(blake2b256(public key))`
+    #[wasm_bindgen(getter, js_name = "publicKeyHash")]
+    pub fn public_key_hash(&self) -> Vec<u8> {
+        self.bytes[2..2 + ADDRESS_HASH_LENGTH as usize].to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn checksum(&self) -> Vec<u8> {
+        self.bytes[ADDRESS_LENGTH as usize - ADDRESS_CHECKSUM_LENGTH as usize..].to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    /// Address encoded in base58
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_b58(&self) -> String {
+        vec_to_b58(self.bytes.clone())
+    }
+
+    /// Fail with `InvalidAddressChain` if the address is not of the chain
+    #[wasm_bindgen(js_name = "checkChain")]
+    pub fn check_chain(&self, chain_id: u8) -> Result<(), LunesError> {
+        self.check_network(chain_id)
+    }
+}
+
+impl Address {
+    /// Address of a public key in a network, see `to_address`
+    pub fn new(public_key: Vec<u8>, network: impl Into<Network>) -> Result<Address, LunesError> {
+        if public_key.len() != PUBLIC_KEY_LENGTH as usize {
+            return Err(LunesError::InvalidKeyLength(public_key.len()));
+        }
+
+        Ok(Address {
+            bytes: to_address(ADDRESS_VERSION[0], network.into().chain_id(), public_key),
+        })
+    }
+
+    /// Network of the chain byte
+    pub fn network(&self) -> Network {
+        Network::from_chain_byte(self.chain_byte())
+    }
+
+    /// Fail with `InvalidAddressChain` if the address is not of the network
+    pub fn check_network(&self, network: impl Into<Network>) -> Result<(), LunesError> {
+        if self.chain_byte() == network.into().chain_byte() {
+            Ok(())
+        } else {
+            Err(LunesError::InvalidAddressChain(self.chain_byte()))
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = LunesError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        Address::from_b58(address.to_string())
+    }
+}
+
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_b58())
+    }
+}
+
+impl From<Address> for Vec<u8> {
+    fn from(address: Address) -> Self {
+        address.bytes
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_b58())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        address.parse().map_err(serde::de::Error::custom)
+    }
+}
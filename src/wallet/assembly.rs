@@ -1,4 +1,8 @@
 use wasm_bindgen::prelude::wasm_bindgen;
+use zeroize::Zeroizing;
+
+use crate::error::LunesError;
+use crate::wallet::secret::{PrivateKey, Seed};
 
 /**
 # Hidden your seed fist with *`blake 2b`* then *`keccak 256`* finally *`sha 256`*
@@ -34,10 +38,65 @@ assert_eq!(
 */
 #[wasm_bindgen(js_name = "hiddenSeed")]
 pub fn hidden_seed(nonce: u32, seed: String) -> Vec<u8> {
-    use crate::utils::crypto::{to_blake2b32b, to_keccak256, to_sha256};
+    hidden_seed_of(nonce, &Seed::from(seed)).to_vec()
+}
+
+/**
+# Hidden Seed of a Seed
+## `hidden_seed` of a typed seed, without copying the phrase
+
+- The hash is wiped from memory when dropped, see `Seed`
 
-    let raw_seed = [nonce.to_be_bytes().to_vec(), seed.as_bytes().to_vec()].concat();
-    to_sha256(to_keccak256(to_blake2b32b(raw_seed)))
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::assembly::{hidden_seed, hidden_seed_of};
+use lunesrs::wallet::secret::Seed;
+
+let phrase = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+assert_eq!(hidden_seed_of(0, &Seed::from(phrase)).to_vec(), hidden_seed(0, phrase.to_string()));
+```
+*/
+pub fn hidden_seed_of(nonce: u32, seed: &Seed) -> Zeroizing<Vec<u8>> {
+    seed.hidden_seed(nonce)
+}
+
+/**
+# Try Hidden Seed
+## Hidden a seed phrase only after validating it
+
+- Normalize and validate the seed phrase with `validate_seed_phrase`
+- Return `hidden_seed` of the canonical phrase, so extra spaces and capitals derive the same wallet
+- Fail with the unknown words instead of deriving a different wallet
+
+## In JavaScript 👍
+
+```javascript
+import * wasm from "lunesrs"
+
+const seed = " Scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar  Benefit"
+
+wasm.checkedHiddenSeed(0, seed) == wasm.hiddenSeed(0, wasm.validateSeed(seed))
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::assembly::{hidden_seed, try_hidden_seed};
+
+let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let typed = " Scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar  Benefit";
+
+assert_eq!(try_hidden_seed(0, typed.to_string()), Ok(hidden_seed(0, seed.to_string())));
+assert_eq!(true, try_hidden_seed(0, "scrub gaurd swim".to_string()).is_err());
+```
+*/
+#[wasm_bindgen(js_name = "checkedHiddenSeed")]
+pub fn try_hidden_seed(nonce: u32, seed: String) -> Result<Vec<u8>, LunesError> {
+    use crate::wallet::mnemonic::validate_seed_phrase;
+
+    validate_seed_phrase(seed).map(|seed| hidden_seed(nonce, seed))
 }
 
 /**
@@ -84,10 +143,9 @@ assert_eq!(
 */
 #[wasm_bindgen(js_name = "toPrivateKey")]
 pub fn to_private_key(hidded_seed: Vec<u8>) -> Vec<u8> {
-    use crate::utils::vectors::{to_vecu32, to_vecu8};
-    use ed25519_axolotl::crypto::keys::KeyPair;
-
-    to_vecu8(KeyPair::new(Some(to_vecu32(hidded_seed))).prvk)
+    PrivateKey::from_hidden_seed(&Zeroizing::new(hidded_seed))
+        .expose_secret()
+        .to_vec()
 }
 
 /**
@@ -134,16 +192,31 @@ assert_eq!(
 */
 #[wasm_bindgen(js_name = "toPublicKey")]
 pub fn to_public_key(private_key: Vec<u8>) -> Vec<u8> {
-    use crate::utils::vectors::{to_vecu32, to_vecu8};
-    use ed25519_axolotl::crypto::keys::KeyPair;
+    public_key_of(&PrivateKey::from(private_key))
+}
+
+/**
+# Public Key of a Private Key
+## `to_public_key` of a typed private key, without copying the key
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::assembly::{public_key_of, to_public_key};
+use lunesrs::wallet::secret::PrivateKey;
 
-    to_vecu8(KeyPair::new(Some(to_vecu32(private_key))).pubk)
+assert_eq!(public_key_of(&PrivateKey::from(vec![1; 32])), to_public_key(vec![1; 32]));
+```
+*/
+pub fn public_key_of(private_key: &PrivateKey) -> Vec<u8> {
+    private_key.public_key()
 }
 
 /**
 # to Lunes Addres from Public Key
 
 - Receive the version, chain and public key and return your lunes address like a bytes
+- The chain is a chain id, `1` in the mainnet, `0` in the testnet, see `Network::from_chain_id`
 - For more details how to works in [telescope](https://blockchain.lunes.io/telescope)
 
 ## In JavaScript 👍
@@ -188,11 +261,12 @@ assert_eq!(
 */
 #[wasm_bindgen(js_name = "toAddress")]
 pub fn to_address(version: u8, chain: u8, public_key: Vec<u8>) -> Vec<u8> {
+    use crate::network::Network;
     use crate::utils::crypto::{to_blake2b32b, to_keccak256};
 
     let raw_addr = {
         let mut pubk = to_keccak256(to_blake2b32b(public_key))[0..20].to_vec();
-        pubk.insert(0, chain.to_string().as_bytes()[0]);
+        pubk.insert(0, Network::from(chain).chain_byte());
         pubk.insert(0, version);
 
         pubk
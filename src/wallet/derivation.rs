@@ -0,0 +1,132 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::network::Network;
+use crate::wallet::account::Account;
+use crate::wallet::secret::Seed;
+
+/// Unused addresses in a row after which `discover_accounts` stops
+pub const DEFAULT_DISCOVERY_GAP: u32 = 20;
+
+/**
+# Accounts
+## Iterator over the accounts of a seed phrase, one per nonce
+
+- Yield `Account::from_seed` of the nonces `start`, `start + 1`, ... with their keys and address
+- Stop after the nonce `u32::MAX`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::account::Account;
+use lunesrs::wallet::derivation::derive_accounts;
+
+let mainnet = 1;
+let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let accounts: Vec<Account> = derive_accounts(seed.to_string(), mainnet, 0).take(3).collect();
+
+assert_eq!(accounts[2].nonce(), Some(2));
+assert_eq!(accounts[2].address(), Account::from_seed(seed.to_string(), 2, mainnet).address());
+```
+*/
+#[derive(Clone)]
+pub struct Accounts {
+    seed: Seed,
+    network: Network,
+    nonce: Option<u32>,
+}
+
+impl Iterator for Accounts {
+    type Item = Account;
+
+    fn next(&mut self) -> Option<Account> {
+        let nonce = self.nonce?;
+        self.nonce = nonce.checked_add(1);
+
+        Some(Account::from_secret_seed(
+            self.seed.clone(),
+            nonce,
+            self.network,
+        ))
+    }
+}
+
+/// Accounts of a seed phrase in a chain from the nonce `start`, see `Accounts`
+pub fn derive_accounts(seed: impl Into<Seed>, network: impl Into<Network>, start: u32) -> Accounts {
+    Accounts {
+        seed: seed.into(),
+        network: network.into(),
+        nonce: Some(start),
+    }
+}
+
+/**
+# Derive Accounts
+## Derive `count` accounts of a seed phrase from the nonce `start`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const mainnet = 1
+const accounts: wasm.Account[] = wasm.deriveAccounts("scrub guard swim ...", mainnet, 0, 5)
+
+accounts.map(account => [account.nonce, account.addressB58])
+```
+*/
+#[wasm_bindgen(js_name = "deriveAccounts")]
+pub fn derive_account_range(seed: String, chain_id: u8, start: u32, count: u32) -> Vec<Account> {
+    derive_accounts(seed, chain_id, start)
+        .take(count as usize)
+        .collect()
+}
+
+/**
+# Discover Accounts
+## Find the used accounts of a seed phrase
+
+- Walk the nonces from `0` and ask `is_used` about every account
+- Stop after `gap` unused accounts in a row, see `DEFAULT_DISCOVERY_GAP`
+- Return the used accounts, or the first error of `is_used`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::derivation::discover_accounts;
+
+let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let used_nonces = [0, 1, 4];
+let used = discover_accounts(seed.to_string(), 1, 3, |account| {
+    Ok::<bool, ()>(used_nonces.contains(&account.nonce().unwrap()))
+})
+.unwrap();
+
+assert_eq!(used.iter().map(|a| a.nonce().unwrap()).collect::<Vec<u32>>(), [0, 1, 4]);
+```
+*/
+pub fn discover_accounts<E, F>(
+    seed: impl Into<Seed>,
+    network: impl Into<Network>,
+    gap: u32,
+    mut is_used: F,
+) -> Result<Vec<Account>, E>
+where
+    F: FnMut(&Account) -> Result<bool, E>,
+{
+    let mut used = vec![];
+    let mut unused = 0;
+
+    for account in derive_accounts(seed, network, 0) {
+        if unused >= gap {
+            break;
+        }
+        if is_used(&account)? {
+            used.push(account);
+            unused = 0;
+        } else {
+            unused += 1;
+        }
+    }
+
+    Ok(used)
+}
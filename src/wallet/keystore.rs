@@ -0,0 +1,367 @@
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+use zeroize::Zeroizing;
+
+use crate::error::LunesError;
+use crate::utils::base58::vec_to_b58;
+use crate::wallet::account::Account;
+use crate::wallet::secret::{PrivateKey, Seed};
+
+/// Version of the keystore format written by `encrypt_keystore`
+pub const KEYSTORE_VERSION: u8 = 1;
+/// Key derivation function of the keystore
+pub const KEYSTORE_KDF: &str = "scrypt";
+/// Authenticated cipher of the keystore
+pub const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+/// Largest scrypt cost accepted when reading a keystore, 1 GiB of memory with `r = 8`
+pub const MAX_SCRYPT_LOG_N: u8 = 20;
+/// Largest scrypt block size accepted when reading a keystore
+pub const MAX_SCRYPT_R: u32 = 32;
+/// Largest scrypt parallelism accepted when reading a keystore
+pub const MAX_SCRYPT_P: u32 = 16;
+/// Largest memory of scrypt, `128 * r * 2 ^ logN` bytes, accepted when reading a keystore
+pub const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// Secret stored in a keystore
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeystoreKind {
+    /// Seed phrase and nonce, the account is derived again with `Account::from_seed`
+    Seed,
+    /// Private key of an account without seed
+    PrivateKey,
+}
+
+/// Cost of scrypt, the defaults take about 32 MiB and a fraction of a second
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        ScryptParams {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// Key derivation and encryption of the secret, byte fields are encoded in base58
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeystoreCrypto {
+    pub kdf: String,
+    pub kdf_params: ScryptParams,
+    #[serde(with = "crate::transactions::json::base58")]
+    pub salt: Vec<u8>,
+    pub cipher: String,
+    #[serde(with = "crate::transactions::json::base58")]
+    pub nonce: Vec<u8>,
+    #[serde(with = "crate::transactions::json::base58")]
+    pub ciphertext: Vec<u8>,
+}
+
+/**
+# Keystore
+## JSON container of an encrypted seed or private key
+
+- `address`, `public_key`, `chain_id` and `nonce` are readable without the password
+- The metadata is authenticated together with the ciphertext, changing it fails the decryption
+
+```json
+{
+  "version": 1,
+  "kind": "seed",
+  "address": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj",
+  "publicKey": "2uPQ...",
+  "chainId": 1,
+  "nonce": 0,
+  "crypto": {
+    "kdf": "scrypt",
+    "kdfParams": { "logN": 15, "r": 8, "p": 1 },
+    "salt": "...",
+    "cipher": "aes-256-gcm",
+    "nonce": "...",
+    "ciphertext": "..."
+  }
+}
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Keystore {
+    pub version: u8,
+    pub kind: KeystoreKind,
+    pub address: String,
+    #[serde(with = "crate::transactions::json::base58")]
+    pub public_key: Vec<u8>,
+    pub chain_id: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u32>,
+    pub crypto: KeystoreCrypto,
+}
+
+impl Keystore {
+    /// Bytes authenticated with the ciphertext, `version | kind | chain | nonce | public key`
+    fn associated_data(&self) -> Vec<u8> {
+        let kind = match self.kind {
+            KeystoreKind::Seed => 0,
+            KeystoreKind::PrivateKey => 1,
+        };
+        let nonce = match self.nonce {
+            Some(nonce) => [vec![1], nonce.to_be_bytes().to_vec()].concat(),
+            None => vec![0],
+        };
+
+        [
+            vec![self.version, kind, self.chain_id],
+            nonce,
+            self.public_key.clone(),
+        ]
+        .concat()
+    }
+}
+
+/// Fail if the scrypt params of an untrusted keystore would take too much memory or time
+fn check_cost(params: ScryptParams) -> Result<(), LunesError> {
+    let memory = 128 * params.r as u64 * (1u64 << params.log_n.min(MAX_SCRYPT_LOG_N));
+    let limits = [
+        ("logN", params.log_n as u64, MAX_SCRYPT_LOG_N as u64),
+        ("r", params.r as u64, MAX_SCRYPT_R as u64),
+        ("p", params.p as u64, MAX_SCRYPT_P as u64),
+        ("memory", memory, MAX_SCRYPT_MEMORY),
+    ];
+
+    match limits.iter().find(|(_, got, max)| got > max) {
+        Some((name, got, max)) => Err(LunesError::InvalidKeystore(format!(
+            "scrypt {} must be at most {}, got {}",
+            name, max, got
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Derive the encryption key of the password with scrypt
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<Zeroizing<Vec<u8>>, LunesError> {
+    check_cost(params)?;
+
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_LENGTH)
+        .map_err(|e| LunesError::InvalidKeystore(e.to_string()))?;
+    let mut key = Zeroizing::new(vec![0; KEY_LENGTH]);
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|e| LunesError::InvalidKeystore(e.to_string()))?;
+
+    Ok(key)
+}
+
+fn random(len: usize) -> Result<Vec<u8>, LunesError> {
+    let mut bytes = vec![0; len];
+    getrandom::getrandom(&mut bytes).map_err(|e| LunesError::Random(e.to_string()))?;
+    Ok(bytes)
+}
+
+fn cipher(key: &[u8]) -> Result<Aes256Gcm, LunesError> {
+    Aes256Gcm::new_from_slice(key).map_err(|e| LunesError::InvalidKeystore(e.to_string()))
+}
+
+/**
+# Encrypt Keystore with Params
+## Encrypt the seed or the private key of an account with a password
+
+- Store the seed phrase and nonce if the account has them, otherwise the private key
+- Fail for watch-only accounts
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::account::Account;
+use lunesrs::wallet::keystore::{decrypt_keystore, encrypt_keystore_with_params, ScryptParams};
+
+let account = Account::from_private_key(vec![1; 32], 1).unwrap();
+let fast = ScryptParams { log_n: 10, r: 8, p: 1 };
+let json = encrypt_keystore_with_params(&account, "secret".to_string(), fast).unwrap();
+
+assert_eq!(decrypt_keystore(json, "secret".to_string()).unwrap().private_key(), account.private_key());
+```
+*/
+pub fn encrypt_keystore_with_params(
+    account: &Account,
+    password: String,
+    params: ScryptParams,
+) -> Result<String, LunesError> {
+    let password = Zeroizing::new(password);
+    let (kind, secret) = match (account.secret_seed(), account.secret_key()) {
+        (Some(seed), _) => (KeystoreKind::Seed, seed.expose_secret().as_bytes()),
+        (None, Some(private_key)) => (KeystoreKind::PrivateKey, private_key.expose_secret()),
+        (None, None) => return Err(LunesError::WatchOnly),
+    };
+    let salt = random(SALT_LENGTH)?;
+    let nonce = random(NONCE_LENGTH)?;
+    let key = derive_key(&password, &salt, params)?;
+
+    let mut keystore = Keystore {
+        version: KEYSTORE_VERSION,
+        kind,
+        address: account.address_b58(),
+        public_key: account.public_key(),
+        chain_id: account.chain_id(),
+        nonce: match kind {
+            KeystoreKind::Seed => account.nonce(),
+            KeystoreKind::PrivateKey => None,
+        },
+        crypto: KeystoreCrypto {
+            kdf: KEYSTORE_KDF.to_string(),
+            kdf_params: params,
+            salt,
+            cipher: KEYSTORE_CIPHER.to_string(),
+            nonce,
+            ciphertext: vec![],
+        },
+    };
+    let payload = Payload {
+        msg: secret,
+        aad: &keystore.associated_data(),
+    };
+    keystore.crypto.ciphertext = cipher(&key)?
+        .encrypt(Nonce::from_slice(&keystore.crypto.nonce), payload)
+        .map_err(|_| LunesError::InvalidKeystore("encryption failed".to_string()))?;
+
+    serde_json::to_string(&keystore).map_err(|e| LunesError::Json(e.to_string()))
+}
+
+/**
+# Encrypt Keystore
+## Encrypt the seed or the private key of an account with a password
+
+- Derive the key with scrypt and the default `ScryptParams`, encrypt with AES-256-GCM
+- Return the JSON of the `Keystore`, safe to write on disk
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const account = wasm.Account.fromSeed("scrub guard swim ...", 0, 1)
+const json: string = wasm.encryptKeystore(account, "password")
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::account::Account;
+use lunesrs::wallet::keystore::{decrypt_keystore, encrypt_keystore};
+
+let account = Account::from_seed("scrub guard swim".to_string(), 0, 1);
+let json = encrypt_keystore(&account, "secret".to_string()).unwrap();
+
+assert_eq!(decrypt_keystore(json, "secret".to_string()).unwrap().seed(), account.seed());
+```
+*/
+#[wasm_bindgen(js_name = "encryptKeystore")]
+pub fn encrypt_keystore(account: &Account, password: String) -> Result<String, LunesError> {
+    encrypt_keystore_with_params(account, password, ScryptParams::default())
+}
+
+/**
+# Decrypt Keystore
+## Restore the account of a keystore with its password
+
+- Fail with `KeystoreDecryption` if the password is wrong or the keystore was changed
+- Fail with `InvalidKeystore` if the version, the kdf or the cipher is not supported,
+  or if the scrypt params are over `MAX_SCRYPT_LOG_N`, `MAX_SCRYPT_R`, `MAX_SCRYPT_P` or `MAX_SCRYPT_MEMORY`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const account: wasm.Account = wasm.decryptKeystore(json, "password")
+account.addressB58
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::error::LunesError;
+use lunesrs::wallet::account::Account;
+use lunesrs::wallet::keystore::{decrypt_keystore, encrypt_keystore_with_params, ScryptParams};
+
+let account = Account::from_seed("scrub guard swim".to_string(), 0, 1);
+let fast = ScryptParams { log_n: 10, r: 8, p: 1 };
+let json = encrypt_keystore_with_params(&account, "secret".to_string(), fast).unwrap();
+
+assert_eq!(true, matches!(decrypt_keystore(json, "wrong".to_string()), Err(LunesError::KeystoreDecryption)));
+```
+*/
+#[wasm_bindgen(js_name = "decryptKeystore")]
+pub fn decrypt_keystore(json: String, password: String) -> Result<Account, LunesError> {
+    let password = Zeroizing::new(password);
+    let keystore: Keystore =
+        serde_json::from_str(&json).map_err(|e| LunesError::InvalidKeystore(e.to_string()))?;
+    let crypto = &keystore.crypto;
+
+    if keystore.version != KEYSTORE_VERSION {
+        return Err(LunesError::InvalidKeystore(format!(
+            "unsupported version {}",
+            keystore.version
+        )));
+    }
+    if crypto.kdf != KEYSTORE_KDF || crypto.cipher != KEYSTORE_CIPHER {
+        return Err(LunesError::InvalidKeystore(format!(
+            "unsupported kdf {} or cipher {}",
+            crypto.kdf, crypto.cipher
+        )));
+    }
+    if crypto.nonce.len() != NONCE_LENGTH {
+        return Err(LunesError::InvalidKeystore(format!(
+            "nonce must have {} bytes, got {}",
+            NONCE_LENGTH,
+            crypto.nonce.len()
+        )));
+    }
+
+    let key = derive_key(&password, &crypto.salt, crypto.kdf_params)?;
+    let payload = Payload {
+        msg: &crypto.ciphertext,
+        aad: &keystore.associated_data(),
+    };
+    let secret = Zeroizing::new(
+        cipher(&key)?
+            .decrypt(Nonce::from_slice(&crypto.nonce), payload)
+            .map_err(|_| LunesError::KeystoreDecryption)?,
+    );
+
+    let account = match (keystore.kind, keystore.nonce) {
+        (KeystoreKind::Seed, Some(nonce)) => {
+            let seed = std::str::from_utf8(&secret).map_err(|_| LunesError::KeystoreDecryption)?;
+            Account::from_secret_seed(Seed::from(seed), nonce, keystore.chain_id)
+        }
+        (KeystoreKind::PrivateKey, None) => {
+            Account::from_secret_key(PrivateKey::from(secret.to_vec()), keystore.chain_id)?
+        }
+        _ => return Err(LunesError::KeystoreDecryption),
+    };
+
+    if account.public_key() != keystore.public_key
+        || vec_to_b58(account.address()) != keystore.address
+    {
+        return Err(LunesError::KeystoreDecryption);
+    }
+
+    Ok(account)
+}
@@ -0,0 +1,199 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::utils::random::random_triple_number;
+use crate::wallet::words::WORD_LIST;
+
+/// Number of words of the seed phrases of lunesjs
+pub const SEED_PHRASE_LENGTH: usize = 15;
+
+/**
+# Generate Seed Phrase
+## Create a new random seed phrase
+
+- Receive the number of words and return them separated by a single space
+- Every three words come from one `random_triple_number`, any word of `WORD_LIST` can be drawn
+- lunesjs uses `15` words, see `SEED_PHRASE_LENGTH`
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const seed: string = wasm.newSeed(15)
+const hiddenSeed: Uint8Array = wasm.hiddenSeed(0, seed)
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::mnemonic::{generate_seed_phrase, SEED_PHRASE_LENGTH};
+use lunesrs::wallet::words::WORD_LIST;
+
+let seed = generate_seed_phrase(SEED_PHRASE_LENGTH);
+
+assert_eq!(seed.split(' ').count(), SEED_PHRASE_LENGTH);
+assert_eq!(true, seed.split(' ').all(|word| WORD_LIST.contains(&word)));
+```
+*/
+#[wasm_bindgen(js_name = "newSeed")]
+pub fn generate_seed_phrase(word_count: usize) -> String {
+    (0..word_count.div_ceil(3))
+        .flat_map(|_| random_triple_number())
+        .take(word_count)
+        .map(|index| WORD_LIST[index as usize])
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Largest edit distance of a word of `WORD_LIST` suggested for an unknown word
+pub const MAX_SUGGESTION_DISTANCE: usize = 2;
+/// Most suggestions given for an unknown word
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Word of a seed phrase that is not in `WORD_LIST`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownWord {
+    /// Position of the word in the phrase, starting at `1` like users count
+    pub position: usize,
+    pub word: String,
+    /// Closest words of `WORD_LIST`, the closest first
+    pub suggestions: Vec<String>,
+}
+
+impl std::fmt::Display for UnknownWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "word {} {:?}", self.position, self.word)?;
+        if !self.suggestions.is_empty() {
+            write!(f, " (did you mean {}?)", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/**
+# Normalize Seed Phrase
+## Remove extra whitespace and capitals of a seed phrase
+
+- Split the phrase on any whitespace and join the lowercase words with a single space
+- Do not check the words, see `validate_seed_phrase`
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::mnemonic::normalize_seed_phrase;
+
+assert_eq!(normalize_seed_phrase("  Scrub\tGUARD \n swim "), "scrub guard swim");
+```
+*/
+pub fn normalize_seed_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/**
+# Validate Seed Phrase
+## Check that every word of a seed phrase is in the word list
+
+- Receive a seed phrase like users type it and return the canonical phrase, see `normalize_seed_phrase`
+- Fail with the position of every unknown word and the closest words of `WORD_LIST`
+- Fail if the phrase has no words
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const seed: string = wasm.validateSeed("  Scrub guard SWIM ")
+seed === "scrub guard swim"
+
+wasm.validateSeed("scrub gaurd swim") // throws Error: ... word 2 "gaurd" (did you mean guard, card, gauge?)
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::error::LunesError;
+use lunesrs::wallet::mnemonic::{validate_seed_phrase, UnknownWord};
+
+assert_eq!(validate_seed_phrase("  Scrub guard SWIM ".to_string()), Ok("scrub guard swim".to_string()));
+assert_eq!(
+    validate_seed_phrase("scrub gaurd swim".to_string()),
+    Err(LunesError::InvalidSeedPhrase(vec![UnknownWord {
+        position: 2,
+        word: "gaurd".to_string(),
+        suggestions: vec!["guard".to_string(), "card".to_string(), "gauge".to_string()],
+    }]))
+);
+```
+*/
+#[wasm_bindgen(js_name = "validateSeed")]
+pub fn validate_seed_phrase(phrase: String) -> Result<String, LunesError> {
+    let phrase = normalize_seed_phrase(&phrase);
+    if phrase.is_empty() {
+        return Err(LunesError::EmptySeedPhrase);
+    }
+
+    let unknown: Vec<UnknownWord> = phrase
+        .split(' ')
+        .enumerate()
+        .filter(|(_, word)| WORD_LIST.binary_search(word).is_err())
+        .map(|(index, word)| UnknownWord {
+            position: index + 1,
+            word: word.to_string(),
+            suggestions: suggestions(word),
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(phrase)
+    } else {
+        Err(LunesError::InvalidSeedPhrase(unknown))
+    }
+}
+
+/// Closest words of `WORD_LIST` to an unknown word
+fn suggestions(word: &str) -> Vec<String> {
+    let mut close: Vec<(usize, &str)> = WORD_LIST
+        .iter()
+        .map(|candidate| (distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    close.sort();
+
+    close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// Edit distance between two words, a swap of two neighbour letters costs one edit
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
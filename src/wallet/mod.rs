@@ -1,9 +1,29 @@
 /// Sign and validate signatures
 pub mod signatures;
+/// Account with its seed, keys and address
+pub mod account;
+/// Address type checked when it is parsed
+pub mod address;
 /// Generate private and public keys
 pub mod assembly;
+/// Derive and discover the accounts of a seed phrase
+pub mod derivation;
+/// Encrypt seeds and private keys with a password
+#[cfg(feature = "keystore")]
+pub mod keystore;
+/// Generate seed phrases from the word list
+pub mod mnemonic;
+/// Seed phrases and private keys wiped from memory when dropped
+pub mod secret;
+/// Search addresses matching a prefix, a suffix or a regular expression
+#[cfg(feature = "vanity")]
+pub mod vanity;
+/// Word list of the seed phrases
+pub mod words;
 
 pub const ADDRESS_VERSION: [u8; 2] = [1, 11];
 pub const ADDRESS_CHECKSUM_LENGTH: u8 = 4;
 pub const ADDRESS_HASH_LENGTH: u8 = 20;
 pub const ADDRESS_LENGTH: u8 = 1 + 1 + ADDRESS_CHECKSUM_LENGTH + ADDRESS_HASH_LENGTH;
+pub const PRIVATE_KEY_LENGTH: u8 = 32;
+pub const PUBLIC_KEY_LENGTH: u8 = 32;
@@ -0,0 +1,186 @@
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::error::LunesError;
+
+/**
+# Seed
+## Seed phrase that is wiped from memory when dropped
+
+- `Debug` never prints the phrase, read it with `expose_secret`
+- Build it from a `String` or a `&str`, every function that takes a seed accepts it
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::assembly::hidden_seed;
+use lunesrs::wallet::secret::Seed;
+
+let phrase = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+let seed = Seed::from(phrase);
+
+assert_eq!(format!("{:?}", seed), "Seed(<redacted>)");
+assert_eq!(seed.hidden_seed(0).to_vec(), hidden_seed(0, phrase.to_string()));
+```
+*/
+#[derive(Clone, PartialEq, Eq)]
+pub struct Seed(String);
+
+impl Seed {
+    /// The seed phrase, keep the copies short-lived
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Normalized seed phrase, fail with the unknown words, see `validate_seed_phrase`
+    pub fn validate(&self) -> Result<Seed, LunesError> {
+        use crate::wallet::mnemonic::validate_seed_phrase;
+
+        validate_seed_phrase(self.0.clone()).map(Seed)
+    }
+
+    /// Hash of the nonce and the phrase, see `hidden_seed`
+    pub fn hidden_seed(&self, nonce: u32) -> Zeroizing<Vec<u8>> {
+        use crate::utils::crypto::{blake2b32b, keccak256, sha256};
+
+        let raw_seed = Zeroizing::new([&nonce.to_be_bytes(), self.0.as_bytes()].concat());
+        let blake = Zeroizing::new(blake2b32b(&raw_seed).unwrap_or_else(|e| panic!("{}", e)));
+        let keccak = Zeroizing::new(keccak256(&blake));
+
+        Zeroizing::new(sha256(&keccak))
+    }
+
+    /// Private key of the `nonce` of the phrase
+    pub fn private_key(&self, nonce: u32) -> PrivateKey {
+        PrivateKey::from_hidden_seed(&self.hidden_seed(nonce))
+    }
+}
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for Seed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Seed(<redacted>)")
+    }
+}
+
+impl From<String> for Seed {
+    fn from(phrase: String) -> Self {
+        Seed(phrase)
+    }
+}
+
+impl From<&str> for Seed {
+    fn from(phrase: &str) -> Self {
+        Seed(phrase.to_string())
+    }
+}
+
+/**
+# Private Key
+## Private key that is wiped from memory when dropped
+
+- `Debug` never prints the key, read it with `expose_secret`
+- The key pairs built for it are wiped, the copies made inside ed25519-axolotl are out of reach
+- Build it from a `Vec<u8>`, every function that takes a private key accepts it
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::assembly::to_public_key;
+use lunesrs::wallet::secret::PrivateKey;
+use lunesrs::wallet::signatures::validate_signature;
+
+let private_key = PrivateKey::from(vec![1; 32]);
+let msg = "Lunes".as_bytes().to_vec();
+let signature = private_key.sign(msg.clone());
+
+assert_eq!(format!("{:?}", private_key), "PrivateKey(<redacted>)");
+assert_eq!(private_key.public_key(), to_public_key(vec![1; 32]));
+assert_eq!(true, validate_signature(private_key.public_key(), msg, signature));
+```
+*/
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKey(Vec<u8>);
+
+impl PrivateKey {
+    /// Private key of a hidden seed, see `to_private_key`
+    pub fn from_hidden_seed(hidden_seed: &[u8]) -> PrivateKey {
+        use ed25519_axolotl::crypto::keys::KeyPair;
+
+        let mut key_pair = KeyPair::new(Some(widen(hidden_seed).to_vec()));
+        let private_key = PrivateKey(key_pair.prvk.iter().map(|x| *x as u8).collect());
+        key_pair.prvk.zeroize();
+
+        private_key
+    }
+
+    /// The private key like bytes, keep the copies short-lived
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Public key of the private key, see `to_public_key`
+    pub fn public_key(&self) -> Vec<u8> {
+        use crate::utils::vectors::to_vecu8;
+        use ed25519_axolotl::crypto::keys::KeyPair;
+
+        let mut key_pair = KeyPair::new(Some(widen(&self.0).to_vec()));
+        key_pair.prvk.zeroize();
+
+        to_vecu8(std::mem::take(&mut key_pair.pubk))
+    }
+
+    /// 64 byte signature of the message, see `fast_signature`
+    pub fn sign(&self, msg: Vec<u8>) -> Vec<u8> {
+        use crate::utils::vectors::{to_vecu32, to_vecu8};
+        use ed25519_axolotl::{crypto::signatures::fast_signature, utils::random::random_bytes};
+
+        to_vecu8(fast_signature(
+            widen(&self.0).to_vec(),
+            to_vecu32(msg),
+            Some(random_bytes(64)),
+        ))
+    }
+
+    /// Signature holding the message, see `full_signature`
+    pub fn full_sign(&self, msg: Vec<u8>) -> Vec<u8> {
+        use crate::utils::vectors::{to_vecu32, to_vecu8};
+        use ed25519_axolotl::{crypto::signatures::full_signature, utils::random::random_bytes};
+
+        to_vecu8(full_signature(
+            widen(&self.0).to_vec(),
+            to_vecu32(msg),
+            Some(random_bytes(64)),
+        ))
+    }
+}
+
+/// Bytes of a secret like the `Vec<u32>` of ed25519-axolotl, wiped when dropped
+///
+/// The functions of ed25519-axolotl take the key by value and drop it without wiping,
+/// so only the copy handed to them is out of reach
+fn widen(secret: &[u8]) -> Zeroizing<Vec<u32>> {
+    Zeroizing::new(secret.iter().map(|x| *x as u32).collect())
+}
+
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "PrivateKey(<redacted>)")
+    }
+}
+
+impl From<Vec<u8>> for PrivateKey {
+    fn from(bytes: Vec<u8>) -> Self {
+        PrivateKey(bytes)
+    }
+}
@@ -1,6 +1,8 @@
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use crate::utils::crypto::{to_blake2b32b, to_keccak256};
+use crate::error::LunesError;
+use crate::utils::crypto::{to_keccak256, try_to_blake2b32b};
+use crate::wallet::secret::PrivateKey;
 
 /**
 # Full Signature
@@ -40,14 +42,12 @@ assert_eq!(true, validate_signature(pubk, msg, signature));
 */
 #[wasm_bindgen(js_name = "fullSignature")]
 pub fn full_signature(private_key: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
-    use crate::utils::vectors::{to_vecu32, to_vecu8};
-    use ed25519_axolotl::{crypto::signatures::full_signature, utils::random::random_bytes};
-
-    to_vecu8(full_signature(
-        to_vecu32(private_key),
-        to_vecu32(msg),
-        Some(random_bytes(64)),
-    ))
+    full_signature_of(&PrivateKey::from(private_key), msg)
+}
+
+/// `full_signature` with a typed private key, without copying the key
+pub fn full_signature_of(private_key: &PrivateKey, msg: Vec<u8>) -> Vec<u8> {
+    private_key.full_sign(msg)
 }
 
 /**
@@ -88,14 +88,12 @@ assert_eq!(true, validate_signature(pubk, msg, signature));
 */
 #[wasm_bindgen(js_name = "fastSignature")]
 pub fn fast_signature(private_key: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
-    use crate::utils::vectors::{to_vecu32, to_vecu8};
-    use ed25519_axolotl::{crypto::signatures::fast_signature, utils::random::random_bytes};
-
-    to_vecu8(fast_signature(
-        to_vecu32(private_key),
-        to_vecu32(msg),
-        Some(random_bytes(64)),
-    ))
+    fast_signature_of(&PrivateKey::from(private_key), msg)
+}
+
+/// `fast_signature` with a typed private key, without copying the key
+pub fn fast_signature_of(private_key: &PrivateKey, msg: Vec<u8>) -> Vec<u8> {
+    private_key.sign(msg)
 }
 
 /**
@@ -148,9 +146,7 @@ pub fn validate_signature(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<
 ## Validate an Address of a given Chain id
 
 - Receive an address and a chain id end return bool
-- ChainID:
-    - MAINNET = 1
-    - TESTNET = 0
+- `chain_id` is the chain id of `Network::chain_id`, in Rust see `Address::check_network`
 
 ## In JavaScript 👍
 
@@ -177,24 +173,72 @@ assert_eq!(true, validate_address(mainnet, addr));
 */
 #[wasm_bindgen(js_name = "validateAddress")]
 pub fn validate_address(chain_id: u8, address: Vec<u8>) -> bool {
+    try_validate_address(chain_id, address).is_ok()
+}
+
+/**
+# Try Validate Address
+## Validate an Address of a given Chain id and tell why it is invalid
+
+- Receive an address and a chain id end return the reason the address is invalid
+- Never panic, whatever the length of the address
+- Throw an `Error` in JavaScript
+
+## In JavaScript 👍
+
+```javascript
+import * wasm from "lunesrs"
+
+const addr = wasm.base58ToArray("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj")
+const testnet = 0
+
+wasm.checkAddress(testnet, addr) // throws Error
+```
+
+## In Rust 🤝
+
+```rust
+use lunesrs::error::LunesError;
+use lunesrs::wallet::signatures::try_validate_address;
+use lunesrs::utils::base58::b58_to_vec;
+
+let mainnet = 1;
+let testnet = 0;
+let addr = b58_to_vec("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string());
+
+assert_eq!(try_validate_address(mainnet, addr.clone()), Ok(()));
+assert_eq!(try_validate_address(testnet, addr), Err(LunesError::InvalidAddressChain(49)));
+assert_eq!(try_validate_address(mainnet, vec![1]), Err(LunesError::InvalidAddressLength(1)));
+```
+*/
+#[wasm_bindgen(js_name = "checkAddress")]
+pub fn try_validate_address(chain_id: u8, address: Vec<u8>) -> Result<(), LunesError> {
+    use crate::network::Network;
+
+    check_address(&address, Some(Network::from(chain_id).chain_byte()))
+}
+
+/// Rules of `try_validate_address`, the chain is only checked if `chain_byte` is given
+pub(crate) fn check_address(address: &[u8], chain_byte: Option<u8>) -> Result<(), LunesError> {
     use crate::wallet::{ADDRESS_CHECKSUM_LENGTH, ADDRESS_LENGTH, ADDRESS_VERSION};
 
-    let (address_left, checksum) = {
-        let x = address.len() - ADDRESS_CHECKSUM_LENGTH as usize;
-        (address[..x].to_vec(), address[x..].to_vec())
-    };
-    let hash_address_left = to_keccak256(to_blake2b32b(address_left.clone()));
-    let chain = &hash_address_left[..4];
-
-    if address.len() as u8 != ADDRESS_LENGTH {
-        return false;
-    } else if address[1] != chain_id.to_string().as_bytes()[0] {
-        return false;
-    } else if ADDRESS_VERSION.contains(&address[0]) == false {
-        return false;
-    } else if checksum != chain {
-        return false;
+    if address.len() != ADDRESS_LENGTH as usize {
+        return Err(LunesError::InvalidAddressLength(address.len()));
+    }
+    if !ADDRESS_VERSION.contains(&address[0]) {
+        return Err(LunesError::InvalidAddressVersion(address[0]));
+    }
+    if chain_byte.is_some_and(|chain_byte| address[1] != chain_byte) {
+        return Err(LunesError::InvalidAddressChain(address[1]));
+    }
+
+    let (address_left, checksum) =
+        address.split_at(address.len() - ADDRESS_CHECKSUM_LENGTH as usize);
+    let hash_address_left = to_keccak256(try_to_blake2b32b(address_left.to_vec())?);
+
+    if checksum == &hash_address_left[..ADDRESS_CHECKSUM_LENGTH as usize] {
+        Ok(())
     } else {
-        return true;
+        Err(LunesError::InvalidAddressChecksum)
     }
 }
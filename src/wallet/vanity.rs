@@ -0,0 +1,399 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use regex::Regex;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::LunesError;
+use crate::network::Network;
+use crate::utils::base58::vec_to_b58;
+use crate::wallet::account::Account;
+use crate::wallet::assembly::to_address;
+use crate::wallet::mnemonic::{generate_seed_phrase, SEED_PHRASE_LENGTH};
+use crate::wallet::secret::Seed;
+use crate::wallet::ADDRESS_VERSION;
+
+/// Alphabet of the base58 addresses
+pub const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// Attempts between two calls of the progress of `search_vanity`
+pub const DEFAULT_PROGRESS_INTERVAL: u64 = 10000;
+
+/// Pattern searched on the base58 form of the addresses
+#[derive(Debug, Clone)]
+pub enum VanityPattern {
+    /// The address starts with the text, mainnet addresses always start with `3`
+    Prefix(String),
+    /// The address ends with the text
+    Suffix(String),
+    /// The address matches the regular expression
+    Regex(Regex),
+}
+
+impl VanityPattern {
+    /// Prefix pattern, fail if the text is not base58
+    pub fn prefix(text: &str) -> Result<VanityPattern, LunesError> {
+        check_base58(text).map(|()| VanityPattern::Prefix(text.to_string()))
+    }
+
+    /// Suffix pattern, fail if the text is not base58
+    pub fn suffix(text: &str) -> Result<VanityPattern, LunesError> {
+        check_base58(text).map(|()| VanityPattern::Suffix(text.to_string()))
+    }
+
+    /// Regular expression pattern, fail if it does not compile
+    pub fn regex(regex: &str) -> Result<VanityPattern, LunesError> {
+        Regex::new(regex)
+            .map(VanityPattern::Regex)
+            .map_err(|e| LunesError::InvalidVanityPattern(e.to_string()))
+    }
+
+    /// `true` if the base58 address matches the pattern
+    pub fn is_match(&self, address: &str) -> bool {
+        match self {
+            VanityPattern::Prefix(prefix) => address.starts_with(prefix.as_str()),
+            VanityPattern::Suffix(suffix) => address.ends_with(suffix.as_str()),
+            VanityPattern::Regex(regex) => regex.is_match(address),
+        }
+    }
+
+    /**
+    # Difficulty
+    ## Expected attempts to find an address of the network matching the pattern
+
+    - A prefix is computed from the addresses the network can have, mainnet addresses go from `37j` to `388`
+    - A suffix falls in the checksum, every character multiplies the attempts by 58
+    - `f64::INFINITY` if no address of the network can match, `None` for regular expressions
+
+    ## In Rust 🤝
+
+    ```rust
+    use lunesrs::network::Network;
+    use lunesrs::wallet::vanity::VanityPattern;
+
+    let difficulty = |pattern: &str| pattern.parse::<VanityPattern>().unwrap().difficulty(Network::Mainnet);
+
+    assert_eq!(difficulty("3"), Some(1.0));
+    assert_eq!(difficulty("suffix:ab"), Some(58.0 * 58.0));
+    assert_eq!(difficulty("37L"), Some(f64::INFINITY));
+    assert_eq!(true, difficulty("37k").unwrap() > 1.0);
+    assert_eq!(difficulty("regex:^37.*Z$"), None);
+    ```
+    */
+    pub fn difficulty(&self, network: Network) -> Option<f64> {
+        match self {
+            VanityPattern::Prefix(prefix) => Some(1.0 / prefix_probability(prefix, network)),
+            VanityPattern::Suffix(suffix) => Some(58f64.powi(suffix.len() as i32)),
+            VanityPattern::Regex(_) => None,
+        }
+    }
+}
+
+impl FromStr for VanityPattern {
+    type Err = LunesError;
+
+    /// Parse `prefix:<text>`, `suffix:<text>`, `regex:<expression>` or a prefix without kind
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(text) = s.strip_prefix("prefix:") {
+            VanityPattern::prefix(text)
+        } else if let Some(text) = s.strip_prefix("suffix:") {
+            VanityPattern::suffix(text)
+        } else if let Some(regex) = s.strip_prefix("regex:") {
+            VanityPattern::regex(regex)
+        } else {
+            VanityPattern::prefix(s)
+        }
+    }
+}
+
+fn check_base58(text: &str) -> Result<(), LunesError> {
+    match text.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        Some(c) => Err(LunesError::InvalidVanityPattern(format!(
+            "{:?} is not a base58 character",
+            c
+        ))),
+        None if text.is_empty() => Err(LunesError::InvalidVanityPattern(
+            "pattern is empty".to_string(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Share of the addresses of the network whose base58 form starts with the prefix
+///
+/// - Addresses are the numbers from `version | chain | 0...` to `version | chain | 255...`
+/// - A prefix of `k` characters in a base58 text of `n` characters is a range of `58^(n - k)` numbers
+fn prefix_probability(prefix: &str, network: Network) -> f64 {
+    if prefix.is_empty() {
+        return 1.0;
+    }
+    if prefix.starts_with('1') {
+        return 0.0;
+    }
+
+    let span = 256f64.powi(24);
+    let low = (ADDRESS_VERSION[0] as f64 * 256.0 + network.chain_byte() as f64) * span;
+    let high = low + span;
+    let value = prefix.bytes().fold(0.0, |value, c| {
+        value * 58.0 + BASE58_ALPHABET.bytes().position(|x| x == c).unwrap_or(0) as f64
+    });
+
+    let mut overlap = 0.0;
+    let mut unit = 1.0;
+    while value * unit < high {
+        let start = (value * unit).max(low);
+        let end = ((value + 1.0) * unit).min(high);
+        overlap += (end - start).max(0.0);
+        unit *= 58.0;
+    }
+
+    overlap / span
+}
+
+/// Settings of `search_vanity`
+#[derive(Debug, Clone)]
+pub struct VanityOptions {
+    pub network: Network,
+    /// Threads searching at the same time, always one in WebAssembly
+    pub threads: usize,
+    /// Words of the random seed phrases, see `SEED_PHRASE_LENGTH`
+    pub word_count: usize,
+    /// Nonces tried for every seed, `1` keeps the address at the nonce `0` used by the wallets
+    pub nonces_per_seed: u32,
+    /// Stop without result after this number of attempts
+    pub max_attempts: Option<u64>,
+    /// Attempts between two calls of the progress
+    pub progress_interval: u64,
+}
+
+impl Default for VanityOptions {
+    fn default() -> Self {
+        VanityOptions {
+            network: Network::Mainnet,
+            threads: default_threads(),
+            word_count: SEED_PHRASE_LENGTH,
+            nonces_per_seed: 1,
+            max_attempts: None,
+            progress_interval: DEFAULT_PROGRESS_INTERVAL,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_threads() -> usize {
+    1
+}
+
+/// Progress of `search_vanity`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VanityProgress {
+    /// Addresses derived until now by all threads
+    pub attempts: u64,
+    /// Expected attempts, see `VanityPattern::difficulty`
+    pub difficulty: Option<f64>,
+}
+
+impl VanityProgress {
+    /// Chance to have found an address after `attempts`, `None` for regular expressions
+    pub fn probability(&self) -> Option<f64> {
+        self.difficulty
+            .map(|difficulty| 1.0 - (1.0 - 1.0 / difficulty).powf(self.attempts as f64))
+    }
+}
+
+/// Account found by `search_vanity`
+#[derive(Debug, Clone)]
+pub struct VanityMatch {
+    pub account: Account,
+    /// Addresses derived by all threads until the match
+    pub attempts: u64,
+}
+
+struct Search<'a, F> {
+    pattern: &'a VanityPattern,
+    options: &'a VanityOptions,
+    difficulty: Option<f64>,
+    attempts: AtomicU64,
+    stop: AtomicBool,
+    progress: Mutex<F>,
+}
+
+/**
+# Search Vanity
+## Find an account whose address matches a pattern
+
+- Generate random seed phrases and derive their addresses with `hidden_seed`, `to_private_key`, `to_public_key` and `to_address`
+- Search with `options.threads` threads on native targets and in the calling thread in WebAssembly
+- Call `progress` every `options.progress_interval` attempts, return `false` from it to cancel
+- Return `None` if the search is cancelled or reaches `options.max_attempts`
+- Fail if the pattern can not match any address of the network
+
+## In Rust 🤝
+
+```rust
+use lunesrs::wallet::vanity::{search_vanity, VanityOptions, VanityPattern};
+
+let pattern: VanityPattern = "suffix:L".parse().unwrap();
+let options = VanityOptions { threads: 2, progress_interval: 10, ..VanityOptions::default() };
+let found = search_vanity(&pattern, &options, |progress| {
+    println!("{} attempts, {:?} expected", progress.attempts, progress.difficulty);
+    true
+})
+.unwrap()
+.unwrap();
+
+assert_eq!(true, found.account.address_b58().ends_with('L'));
+assert_eq!(found.account.nonce(), Some(0));
+```
+*/
+pub fn search_vanity<F>(
+    pattern: &VanityPattern,
+    options: &VanityOptions,
+    progress: F,
+) -> Result<Option<VanityMatch>, LunesError>
+where
+    F: FnMut(&VanityProgress) -> bool + Send,
+{
+    let difficulty = pattern.difficulty(options.network);
+    if difficulty == Some(f64::INFINITY) {
+        return Err(LunesError::InvalidVanityPattern(format!(
+            "{:?} can not match an address of the {} network",
+            pattern, options.network
+        )));
+    }
+
+    let search = Search {
+        pattern,
+        options,
+        difficulty,
+        attempts: AtomicU64::new(0),
+        stop: AtomicBool::new(false),
+        progress: Mutex::new(progress),
+    };
+
+    Ok(run(&search))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run<F>(search: &Search<F>) -> Option<VanityMatch>
+where
+    F: FnMut(&VanityProgress) -> bool + Send,
+{
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..search.options.threads.max(1))
+            .map(|_| scope.spawn(|| search.work()))
+            .collect();
+
+        workers
+            .into_iter()
+            .filter_map(|worker| worker.join().ok().flatten())
+            .min_by_key(|found| found.attempts)
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn run<F>(search: &Search<F>) -> Option<VanityMatch>
+where
+    F: FnMut(&VanityProgress) -> bool + Send,
+{
+    search.work()
+}
+
+impl<F> Search<'_, F>
+where
+    F: FnMut(&VanityProgress) -> bool,
+{
+    fn work(&self) -> Option<VanityMatch> {
+        let network = self.options.network;
+
+        while !self.stop.load(Ordering::Relaxed) {
+            let seed = Seed::from(generate_seed_phrase(self.options.word_count));
+
+            for nonce in 0..self.options.nonces_per_seed.max(1) {
+                let attempts = self.attempts.fetch_add(1, Ordering::Relaxed) + 1;
+                if self.stop.load(Ordering::Relaxed)
+                    || self.options.max_attempts.is_some_and(|max| attempts > max)
+                {
+                    self.stop.store(true, Ordering::Relaxed);
+                    return None;
+                }
+
+                let public_key = seed.private_key(nonce).public_key();
+                let address = to_address(ADDRESS_VERSION[0], network.chain_id(), public_key);
+                if self.pattern.is_match(&vec_to_b58(address)) {
+                    self.stop.store(true, Ordering::Relaxed);
+                    return Some(VanityMatch {
+                        account: Account::from_secret_seed(seed, nonce, network),
+                        attempts,
+                    });
+                }
+
+                // `u64::is_multiple_of` needs Rust 1.87
+                #[allow(clippy::manual_is_multiple_of)]
+                if attempts % self.options.progress_interval.max(1) == 0 && !self.report(attempts) {
+                    self.stop.store(true, Ordering::Relaxed);
+                    return None;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn report(&self, attempts: u64) -> bool {
+        let progress = VanityProgress {
+            attempts,
+            difficulty: self.difficulty,
+        };
+
+        match self.progress.lock() {
+            Ok(mut progress_fn) => progress_fn(&progress),
+            Err(_) => false,
+        }
+    }
+}
+
+/**
+# Find Vanity Account
+## Find an account whose address matches a pattern, see `search_vanity`
+
+- The pattern is `prefix:<text>`, `suffix:<text>`, `regex:<expression>` or a prefix
+- Return `undefined` after `max_attempts` attempts
+
+## In JavaScript 👍
+
+```javascript
+import * as wasm from "lunesrs"
+
+const mainnet = 1
+wasm.vanityDifficulty("37k", mainnet) // expected attempts
+const account: wasm.Account | undefined = wasm.findVanityAccount("37k", mainnet, 1000000n)
+```
+*/
+#[wasm_bindgen(js_name = "findVanityAccount")]
+pub fn find_vanity_account(
+    pattern: String,
+    chain_id: u8,
+    max_attempts: u64,
+) -> Result<Option<Account>, LunesError> {
+    let options = VanityOptions {
+        network: Network::from(chain_id),
+        max_attempts: Some(max_attempts),
+        ..VanityOptions::default()
+    };
+
+    search_vanity(&pattern.parse()?, &options, |_| true).map(|found| found.map(|x| x.account))
+}
+
+/// Expected attempts of `findVanityAccount`, see `VanityPattern::difficulty`
+#[wasm_bindgen(js_name = "vanityDifficulty")]
+pub fn vanity_difficulty(pattern: String, chain_id: u8) -> Result<Option<f64>, LunesError> {
+    Ok(pattern
+        .parse::<VanityPattern>()?
+        .difficulty(Network::from(chain_id)))
+}
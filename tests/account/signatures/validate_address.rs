@@ -11,3 +11,49 @@ fn addr_validation() {
 
     assert_eq!(true, validate_address(mainnet, addr));
 }
+
+#[test]
+#[wasm_bindgen_test]
+fn addr_validation_never_panics() {
+    use lunesrs::wallet::signatures::validate_address;
+
+    let mainnet = 1;
+
+    assert_eq!(false, validate_address(mainnet, vec![]));
+    assert_eq!(false, validate_address(mainnet, vec![1, 49, 0]));
+    assert_eq!(false, validate_address(mainnet, vec![1; 40]));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn addr_validation_errors() {
+    use lunesrs::error::LunesError;
+    use lunesrs::utils::base58::b58_to_vec;
+    use lunesrs::wallet::signatures::try_validate_address;
+
+    let mainnet = 1;
+    let testnet = 0;
+    let addr = b58_to_vec("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string());
+    let mut version = addr.clone();
+    version[0] = 2;
+    let mut checksum = addr.clone();
+    checksum[25] ^= 1;
+
+    assert_eq!(try_validate_address(mainnet, addr.clone()), Ok(()));
+    assert_eq!(
+        try_validate_address(mainnet, vec![1, 49]),
+        Err(LunesError::InvalidAddressLength(2))
+    );
+    assert_eq!(
+        try_validate_address(mainnet, version),
+        Err(LunesError::InvalidAddressVersion(2))
+    );
+    assert_eq!(
+        try_validate_address(testnet, addr),
+        Err(LunesError::InvalidAddressChain(49))
+    );
+    assert_eq!(
+        try_validate_address(mainnet, checksum),
+        Err(LunesError::InvalidAddressChecksum)
+    );
+}
@@ -0,0 +1,107 @@
+use lunesrs::wallet::account::Account;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+#[test]
+#[wasm_bindgen_test]
+fn account_from_seed() {
+    use lunesrs::utils::base58::vec_to_b58;
+
+    let mainnet = 1;
+    let account = Account::from_seed(SEED.to_string(), 0, mainnet);
+    let private_key = vec![
+        160, 66, 17, 225, 21, 144, 128, 203, 241, 21, 205, 209, 16, 138, 219, 155, 50, 48, 24, 209,
+        227, 79, 35, 104, 252, 102, 213, 74, 63, 165, 20, 96,
+    ];
+    let public_key = vec![
+        28, 105, 36, 199, 36, 111, 120, 95, 152, 208, 215, 39, 161, 71, 78, 237, 200, 160, 71, 209,
+        177, 102, 140, 170, 56, 206, 9, 214, 227, 38, 117, 117,
+    ];
+    let address = vec![
+        1, 49, 44, 46, 82, 88, 220, 91, 204, 187, 92, 83, 89, 68, 39, 15, 115, 185, 143, 151, 57,
+        38, 99, 41, 200, 192,
+    ];
+
+    assert_eq!(account.seed(), Some(SEED.to_string()));
+    assert_eq!(account.nonce(), Some(0));
+    assert_eq!(account.chain_id(), mainnet);
+    assert_eq!(account.private_key(), Some(private_key.clone()));
+    assert_eq!(account.private_key_b58(), Some(vec_to_b58(private_key)));
+    assert_eq!(account.public_key(), public_key.clone());
+    assert_eq!(account.public_key_b58(), vec_to_b58(public_key));
+    assert_eq!(account.address(), address.clone());
+    assert_eq!(account.address_b58(), vec_to_b58(address));
+    assert_eq!(false, account.is_watch_only());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn account_from_keys() {
+    use lunesrs::error::LunesError;
+
+    let testnet = 0;
+    let account = Account::from_seed(SEED.to_string(), 1, testnet);
+    let from_private = Account::from_private_key(account.private_key().unwrap(), testnet).unwrap();
+    let watch = Account::from_public_key(account.public_key(), testnet).unwrap();
+
+    assert_eq!(from_private.address(), account.address());
+    assert_eq!(from_private.seed(), None);
+    assert_eq!(from_private.nonce(), None);
+    assert_eq!(watch.address(), account.address());
+    assert_eq!(watch.private_key(), None);
+    assert_eq!(true, watch.is_watch_only());
+    assert_eq!(
+        true,
+        matches!(
+            Account::from_private_key(vec![1; 31], testnet),
+            Err(LunesError::InvalidKeyLength(31))
+        )
+    );
+    assert_eq!(
+        true,
+        matches!(
+            Account::from_public_key(vec![], testnet),
+            Err(LunesError::InvalidKeyLength(0))
+        )
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn account_signatures() {
+    use lunesrs::error::LunesError;
+    use lunesrs::transactions::lease::LeaseTransaction;
+    use lunesrs::transactions::parser::parse_transaction;
+
+    let account = Account::from_seed(SEED.to_string(), 0, 1);
+    let watch = Account::from_public_key(account.public_key(), 1).unwrap();
+    let msg = "Lunes".as_bytes().to_vec();
+    let tx = LeaseTransaction {
+        sender_public_key: account.public_key(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    let signature = account.sign_message(msg.clone()).unwrap();
+    assert_eq!(true, watch.verify_message(msg.clone(), signature.clone()));
+    assert_eq!(
+        false,
+        watch.verify_message("Lune".as_bytes().to_vec(), signature)
+    );
+
+    let signed = account.sign_transaction(&tx).unwrap();
+    assert_eq!(true, parse_transaction(signed).unwrap().verify());
+
+    assert_eq!(
+        true,
+        matches!(watch.sign_message(msg), Err(LunesError::WatchOnly))
+    );
+    assert_eq!(
+        true,
+        matches!(watch.sign_transaction(&tx), Err(LunesError::WatchOnly))
+    );
+}
@@ -74,3 +74,67 @@ fn single_address() {
         ],
     );
 }
+
+#[test]
+#[wasm_bindgen_test]
+fn address_from_public_key() {
+    use lunesrs::utils::base58::vec_to_b58;
+    use lunesrs::wallet::address::Address;
+
+    let public_key: Vec<u8> = vec![
+        28, 105, 36, 199, 36, 111, 120, 95, 152, 208, 215, 39, 161, 71, 78, 237, 200, 160, 71, 209,
+        177, 102, 140, 170, 56, 206, 9, 214, 227, 38, 117, 117,
+    ];
+    let bytes = to_address(1, 1, public_key.clone());
+    let address = Address::from_public_key(public_key, 1).unwrap();
+
+    assert_eq!(address.bytes(), bytes);
+    assert_eq!(address.version(), 1);
+    assert_eq!(address.chain_byte(), 49);
+    assert_eq!(address.chain_id(), 1);
+    assert_eq!(address.public_key_hash(), bytes[2..22].to_vec());
+    assert_eq!(address.checksum(), bytes[22..].to_vec());
+    assert_eq!(address.to_string(), vec_to_b58(bytes.clone()));
+    assert_eq!(address.to_string().parse::<Address>(), Ok(address.clone()));
+    assert_eq!(Address::from_bytes(bytes), Ok(address.clone()));
+    assert_eq!(address.check_chain(1), Ok(()));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn address_parse_errors() {
+    use lunesrs::error::LunesError;
+    use lunesrs::utils::base58::vec_to_b58;
+    use lunesrs::wallet::address::Address;
+
+    let address = Address::from_public_key(vec![7; 32], 1).unwrap();
+    let mut wrong_checksum = address.bytes();
+    wrong_checksum[25] ^= 1;
+    let mut wrong_version = address.bytes();
+    wrong_version[0] = 2;
+
+    assert_eq!(
+        true,
+        matches!("0OIl".parse::<Address>(), Err(LunesError::InvalidBase58(_)))
+    );
+    assert_eq!(
+        Address::from_bytes(vec![1; 25]),
+        Err(LunesError::InvalidAddressLength(25))
+    );
+    assert_eq!(
+        Address::from_bytes(wrong_version),
+        Err(LunesError::InvalidAddressVersion(2))
+    );
+    assert_eq!(
+        vec_to_b58(wrong_checksum).parse::<Address>(),
+        Err(LunesError::InvalidAddressChecksum)
+    );
+    assert_eq!(
+        Address::from_public_key(vec![7; 31], 1),
+        Err(LunesError::InvalidKeyLength(31))
+    );
+    assert_eq!(
+        address.check_chain(0),
+        Err(LunesError::InvalidAddressChain(49))
+    );
+}
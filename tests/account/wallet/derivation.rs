@@ -0,0 +1,109 @@
+use lunesrs::wallet::account::Account;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+fn nonces(accounts: &[Account]) -> Vec<u32> {
+    accounts
+        .iter()
+        .map(|account| account.nonce().unwrap())
+        .collect()
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn derive_by_nonce() {
+    use lunesrs::wallet::assembly::{hidden_seed, to_address, to_private_key, to_public_key};
+    use lunesrs::wallet::derivation::{derive_account_range, derive_accounts};
+
+    let testnet = 0;
+    let accounts: Vec<Account> = derive_accounts(SEED.to_string(), testnet, 3)
+        .take(3)
+        .collect();
+
+    assert_eq!(nonces(&accounts), [3, 4, 5]);
+    for account in &accounts {
+        let private_key = to_private_key(hidden_seed(account.nonce().unwrap(), SEED.to_string()));
+        let public_key = to_public_key(private_key.clone());
+
+        assert_eq!(account.private_key(), Some(private_key));
+        assert_eq!(
+            account.address(),
+            to_address(1, testnet, public_key.clone())
+        );
+        assert_eq!(account.public_key(), public_key);
+    }
+    assert_eq!(
+        nonces(&derive_account_range(SEED.to_string(), testnet, 3, 3)),
+        [3, 4, 5]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn derive_until_last_nonce() {
+    use lunesrs::wallet::derivation::derive_accounts;
+
+    let accounts: Vec<Account> = derive_accounts(SEED.to_string(), 1, u32::MAX - 1).collect();
+
+    assert_eq!(nonces(&accounts), [u32::MAX - 1, u32::MAX]);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn discover_until_gap() {
+    use lunesrs::wallet::derivation::discover_accounts;
+
+    let used = [0, 2, 5];
+    let mut asked = vec![];
+    let found = discover_accounts(SEED.to_string(), 1, 3, |account| {
+        asked.push(account.nonce().unwrap());
+        Ok::<bool, ()>(used.contains(&account.nonce().unwrap()))
+    })
+    .unwrap();
+
+    assert_eq!(nonces(&found), [0, 2, 5]);
+    assert_eq!(asked, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn discover_by_address_with_a_stub() {
+    use lunesrs::wallet::derivation::{discover_accounts, DEFAULT_DISCOVERY_GAP};
+    use std::collections::HashSet;
+
+    let mainnet = 1;
+    let node: HashSet<String> = [1, 7]
+        .iter()
+        .map(|nonce| Account::from_seed(SEED.to_string(), *nonce, mainnet).address_b58())
+        .collect();
+    let found = discover_accounts(
+        SEED.to_string(),
+        mainnet,
+        DEFAULT_DISCOVERY_GAP,
+        |account| Ok::<bool, ()>(node.contains(&account.address_b58())),
+    )
+    .unwrap();
+
+    assert_eq!(nonces(&found), [1, 7]);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn discover_stops_on_error() {
+    use lunesrs::wallet::derivation::discover_accounts;
+
+    let result = discover_accounts(SEED.to_string(), 1, 5, |account| match account.nonce() {
+        Some(2) => Err("node is offline"),
+        _ => Ok(true),
+    });
+
+    assert_eq!(true, matches!(result, Err("node is offline")));
+    assert_eq!(
+        true,
+        discover_accounts(SEED.to_string(), 1, 0, |_| Ok::<bool, ()>(true))
+            .unwrap()
+            .is_empty()
+    );
+}
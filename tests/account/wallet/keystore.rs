@@ -0,0 +1,170 @@
+use lunesrs::error::LunesError;
+use lunesrs::wallet::account::Account;
+use lunesrs::wallet::keystore::{
+    decrypt_keystore, encrypt_keystore_with_params, Keystore, KeystoreKind, ScryptParams,
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+const FAST: ScryptParams = ScryptParams {
+    log_n: 10,
+    r: 8,
+    p: 1,
+};
+
+fn keystore(account: &Account) -> Keystore {
+    let json = encrypt_keystore_with_params(account, "secret".to_string(), FAST).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn decrypt(keystore: &Keystore, password: &str) -> Result<Account, LunesError> {
+    decrypt_keystore(
+        serde_json::to_string(keystore).unwrap(),
+        password.to_string(),
+    )
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_of_seed() {
+    let account = Account::from_seed(SEED.to_string(), 3, 0);
+    let keystore = keystore(&account);
+    let restored = decrypt(&keystore, "secret").unwrap();
+
+    assert_eq!(keystore.version, 1);
+    assert_eq!(keystore.kind, KeystoreKind::Seed);
+    assert_eq!(keystore.address, account.address_b58());
+    assert_eq!(keystore.public_key, account.public_key());
+    assert_eq!(keystore.chain_id, 0);
+    assert_eq!(keystore.nonce, Some(3));
+    assert_eq!(keystore.crypto.kdf, "scrypt");
+    assert_eq!(keystore.crypto.kdf_params, FAST);
+    assert_eq!(keystore.crypto.cipher, "aes-256-gcm");
+    assert_eq!(restored.seed(), Some(SEED.to_string()));
+    assert_eq!(restored.nonce(), Some(3));
+    assert_eq!(restored.private_key(), account.private_key());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_of_private_key() {
+    let account = Account::from_private_key(vec![7; 32], 1).unwrap();
+    let keystore = keystore(&account);
+    let restored = decrypt(&keystore, "secret").unwrap();
+
+    assert_eq!(keystore.kind, KeystoreKind::PrivateKey);
+    assert_eq!(keystore.nonce, None);
+    assert_eq!(restored.seed(), None);
+    assert_eq!(restored.private_key(), Some(vec![7; 32]));
+    assert_eq!(restored.address(), account.address());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_with_random_salt_and_nonce() {
+    let account = Account::from_private_key(vec![7; 32], 1).unwrap();
+    let first = keystore(&account);
+    let second = keystore(&account);
+
+    assert_ne!(first.crypto.salt, second.crypto.salt);
+    assert_ne!(first.crypto.nonce, second.crypto.nonce);
+    assert_ne!(first.crypto.ciphertext, second.crypto.ciphertext);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_wrong_password() {
+    let keystore = keystore(&Account::from_seed(SEED.to_string(), 0, 1));
+
+    assert_eq!(
+        true,
+        matches!(
+            decrypt(&keystore, "Secret"),
+            Err(LunesError::KeystoreDecryption)
+        )
+    );
+    assert_eq!(
+        true,
+        matches!(decrypt(&keystore, ""), Err(LunesError::KeystoreDecryption))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_tampered() {
+    let original = keystore(&Account::from_seed(SEED.to_string(), 0, 1));
+
+    let mut ciphertext = original.clone();
+    ciphertext.crypto.ciphertext[0] ^= 1;
+    let mut truncated = original.clone();
+    truncated.crypto.ciphertext.pop();
+    let mut nonce = original.clone();
+    nonce.nonce = Some(1);
+    let mut public_key = original.clone();
+    public_key.public_key = Account::from_seed(SEED.to_string(), 1, 1).public_key();
+    let mut address = original.clone();
+    address.address = Account::from_seed(SEED.to_string(), 1, 1).address_b58();
+
+    for tampered in [ciphertext, truncated, nonce, public_key, address] {
+        assert_eq!(
+            true,
+            matches!(
+                decrypt(&tampered, "secret"),
+                Err(LunesError::KeystoreDecryption)
+            )
+        );
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_unsupported() {
+    let original = keystore(&Account::from_seed(SEED.to_string(), 0, 1));
+
+    let mut version = original.clone();
+    version.version = 2;
+    let mut cipher = original.clone();
+    cipher.crypto.cipher = "aes-128-ctr".to_string();
+    let mut cost = original.clone();
+    cost.crypto.kdf_params.log_n = 40;
+    let mut block = original.clone();
+    block.crypto.kdf_params.r = u32::MAX;
+    let mut parallel = original.clone();
+    parallel.crypto.kdf_params.p = u32::MAX;
+    let mut memory = original.clone();
+    memory.crypto.kdf_params.log_n = 20;
+    memory.crypto.kdf_params.r = 32;
+
+    for unsupported in [version, cipher, cost, block, parallel, memory] {
+        assert_eq!(
+            true,
+            matches!(
+                decrypt(&unsupported, "secret"),
+                Err(LunesError::InvalidKeystore(_))
+            )
+        );
+    }
+    assert_eq!(
+        true,
+        matches!(
+            decrypt_keystore("{}".to_string(), "secret".to_string()),
+            Err(LunesError::InvalidKeystore(_))
+        )
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn keystore_of_watch_only() {
+    let account = Account::from_seed(SEED.to_string(), 0, 1);
+    let watch = Account::from_public_key(account.public_key(), 1).unwrap();
+
+    assert_eq!(
+        true,
+        matches!(
+            encrypt_keystore_with_params(&watch, "secret".to_string(), FAST),
+            Err(LunesError::WatchOnly)
+        )
+    );
+}
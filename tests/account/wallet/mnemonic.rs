@@ -0,0 +1,108 @@
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn word_list_of_lunesjs() {
+    use lunesrs::wallet::words::{WORD_COUNT, WORD_LIST};
+
+    let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+    assert_eq!(WORD_LIST.len(), WORD_COUNT);
+    assert_eq!(true, WORD_LIST.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(true, seed.split(' ').all(|word| WORD_LIST.contains(&word)));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn new_seed_phrase() {
+    use lunesrs::wallet::mnemonic::{generate_seed_phrase, SEED_PHRASE_LENGTH};
+    use lunesrs::wallet::words::WORD_LIST;
+
+    let seed = generate_seed_phrase(SEED_PHRASE_LENGTH);
+    let words: Vec<&str> = seed.split(' ').collect();
+
+    assert_eq!(words.len(), 15);
+    assert_eq!(true, words.iter().all(|word| WORD_LIST.contains(word)));
+    assert_ne!(seed, generate_seed_phrase(SEED_PHRASE_LENGTH));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn seed_phrase_of_any_length() {
+    use lunesrs::wallet::mnemonic::generate_seed_phrase;
+
+    assert_eq!(generate_seed_phrase(0), "");
+    assert_eq!(generate_seed_phrase(1).split(' ').count(), 1);
+    assert_eq!(generate_seed_phrase(12).split(' ').count(), 12);
+    assert_eq!(generate_seed_phrase(16).split(' ').count(), 16);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn normalize_whitespace_and_case() {
+    use lunesrs::wallet::mnemonic::{normalize_seed_phrase, validate_seed_phrase};
+
+    let seed = "scrub guard swim";
+
+    assert_eq!(normalize_seed_phrase("\u{a0}Scrub  GUARD\n\tswim "), seed);
+    assert_eq!(
+        validate_seed_phrase(" SCRUB guard   Swim".to_string()),
+        Ok(seed.to_string())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn unknown_words_with_suggestions() {
+    use lunesrs::error::LunesError;
+    use lunesrs::wallet::mnemonic::{validate_seed_phrase, UnknownWord};
+
+    let result = validate_seed_phrase("scrub gaurd swim xyzzyq benefti".to_string());
+
+    assert_eq!(
+        result,
+        Err(LunesError::InvalidSeedPhrase(vec![
+            UnknownWord {
+                position: 2,
+                word: "gaurd".to_string(),
+                suggestions: vec!["guard".to_string(), "card".to_string(), "gauge".to_string()],
+            },
+            UnknownWord {
+                position: 4,
+                word: "xyzzyq".to_string(),
+                suggestions: vec![],
+            },
+            UnknownWord {
+                position: 5,
+                word: "benefti".to_string(),
+                suggestions: vec!["benefit".to_string()],
+            },
+        ]))
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "unknown words in seed phrase: word 2 \"gaurd\" (did you mean guard, card, gauge?), word 4 \"xyzzyq\", word 5 \"benefti\" (did you mean benefit?)"
+    );
+    assert_eq!(
+        validate_seed_phrase(" \n ".to_string()),
+        Err(LunesError::EmptySeedPhrase)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn hidden_seed_of_the_canonical_phrase() {
+    use lunesrs::wallet::assembly::{hidden_seed, try_hidden_seed};
+
+    let seed = "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+    let typed = "Scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit\n";
+
+    assert_eq!(
+        try_hidden_seed(1, typed.to_string()),
+        Ok(hidden_seed(1, seed.to_string()))
+    );
+    assert_eq!(
+        true,
+        try_hidden_seed(1, format!("{} benefti", seed)).is_err()
+    );
+}
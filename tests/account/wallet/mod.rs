@@ -1,4 +1,12 @@
+mod account;
 mod address;
+mod derivation;
 mod hidden_seed;
+#[cfg(feature = "keystore")]
+mod keystore;
+mod mnemonic;
 mod private_key;
 mod public_key;
+mod secret;
+#[cfg(feature = "vanity")]
+mod vanity;
@@ -0,0 +1,142 @@
+use lunesrs::wallet::secret::{PrivateKey, Seed};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+#[test]
+#[wasm_bindgen_test]
+fn secrets_are_redacted_in_debug() {
+    use lunesrs::wallet::account::Account;
+
+    let seed = Seed::from(SEED);
+    let private_key = seed.private_key(0);
+    let account = Account::from_seed(SEED.to_string(), 0, 1);
+    let debug = format!("{:?}", account);
+
+    assert_eq!(format!("{:?}", seed), "Seed(<redacted>)");
+    assert_eq!(format!("{:?}", private_key), "PrivateKey(<redacted>)");
+    assert_eq!(false, debug.contains("scrub"));
+    assert_eq!(false, debug.contains("160, 66, 17"));
+    assert_eq!(true, debug.contains("<redacted>"));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn seed_derives_like_assembly() {
+    use lunesrs::wallet::assembly::{hidden_seed, to_private_key, to_public_key};
+
+    let seed = Seed::from(SEED.to_string());
+    let private_key = to_private_key(hidden_seed(3, SEED.to_string()));
+
+    assert_eq!(
+        seed.hidden_seed(3).to_vec(),
+        hidden_seed(3, SEED.to_string())
+    );
+    assert_eq!(seed.private_key(3).expose_secret(), private_key.as_slice());
+    assert_eq!(seed.private_key(3).public_key(), to_public_key(private_key));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn seed_validate() {
+    use lunesrs::error::LunesError;
+
+    let typed = Seed::from("  Scrub GUARD swim ");
+
+    assert_eq!(
+        typed.validate().unwrap().expose_secret(),
+        "scrub guard swim"
+    );
+    assert_eq!(
+        true,
+        matches!(
+            Seed::from("scrub gaurd swim").validate(),
+            Err(LunesError::InvalidSeedPhrase(_))
+        )
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn private_key_signatures() {
+    use lunesrs::wallet::signatures::validate_signature;
+
+    let private_key = PrivateKey::from(vec![1; 32]);
+    let public_key = private_key.public_key();
+    let msg = "Lunes".as_bytes().to_vec();
+
+    assert_eq!(
+        true,
+        validate_signature(
+            public_key.clone(),
+            msg.clone(),
+            private_key.sign(msg.clone())
+        )
+    );
+    assert_eq!(
+        true,
+        validate_signature(public_key, msg.clone(), private_key.full_sign(msg))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn typed_overloads_match_bytes() {
+    use lunesrs::wallet::assembly::{hidden_seed, hidden_seed_of, public_key_of, to_public_key};
+    use lunesrs::wallet::signatures::{fast_signature_of, full_signature_of, validate_signature};
+
+    let private_key = PrivateKey::from(vec![1; 32]);
+    let public_key = to_public_key(vec![1; 32]);
+    let msg = "Lunes".as_bytes().to_vec();
+
+    assert_eq!(
+        hidden_seed_of(0, &Seed::from(SEED)).to_vec(),
+        hidden_seed(0, SEED.to_string())
+    );
+    assert_eq!(public_key_of(&private_key), public_key);
+    assert_eq!(
+        true,
+        validate_signature(
+            public_key.clone(),
+            msg.clone(),
+            fast_signature_of(&private_key, msg.clone())
+        )
+    );
+    assert_eq!(
+        true,
+        validate_signature(
+            public_key,
+            msg.clone(),
+            full_signature_of(&private_key, msg)
+        )
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transactions_accept_private_key() {
+    use lunesrs::transactions::burn::BurnTransaction;
+    use lunesrs::transactions::parser::parse_transaction;
+    use lunesrs::wallet::account::Account;
+
+    let private_key = PrivateKey::from(vec![1; 32]);
+    let tx = BurnTransaction {
+        sender_public_key: private_key.public_key(),
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let account = Account::from_secret_key(private_key.clone(), 1).unwrap();
+
+    assert_eq!(
+        true,
+        parse_transaction(tx.sign(private_key).unwrap()).is_ok()
+    );
+    assert_eq!(
+        true,
+        parse_transaction(tx.sign(vec![1; 32]).unwrap()).is_ok()
+    );
+    assert_eq!(account.private_key(), Some(vec![1; 32]));
+}
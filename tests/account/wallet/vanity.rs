@@ -0,0 +1,134 @@
+use lunesrs::network::Network;
+use lunesrs::wallet::vanity::{search_vanity, VanityOptions, VanityPattern};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn vanity_patterns() {
+    let addr = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj";
+
+    assert_eq!(true, "37o".parse::<VanityPattern>().unwrap().is_match(addr));
+    assert_eq!(
+        true,
+        "prefix:37o"
+            .parse::<VanityPattern>()
+            .unwrap()
+            .is_match(addr)
+    );
+    assert_eq!(
+        true,
+        "suffix:Aaj"
+            .parse::<VanityPattern>()
+            .unwrap()
+            .is_match(addr)
+    );
+    assert_eq!(
+        false,
+        "suffix:37o"
+            .parse::<VanityPattern>()
+            .unwrap()
+            .is_match(addr)
+    );
+    assert_eq!(
+        true,
+        "regex:^37.*j$"
+            .parse::<VanityPattern>()
+            .unwrap()
+            .is_match(addr)
+    );
+    for invalid in ["", "37O", "suffix:0", "regex:("] {
+        assert_eq!(true, invalid.parse::<VanityPattern>().is_err());
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn vanity_difficulty() {
+    let difficulty = |pattern: &str, network: Network| {
+        pattern
+            .parse::<VanityPattern>()
+            .unwrap()
+            .difficulty(network)
+            .unwrap()
+    };
+
+    assert_eq!(difficulty("3", Network::Mainnet), 1.0);
+    assert_eq!(difficulty("37L", Network::Mainnet), f64::INFINITY);
+    assert_eq!(true, difficulty("37L", Network::Testnet).is_finite());
+    assert_eq!(difficulty("suffix:Lu", Network::Mainnet), 58.0 * 58.0);
+    // "37k" to "37z" and "381" to "388" share the mainnet, each prefix of 4 characters is rarer
+    assert_eq!(
+        true,
+        difficulty("37k", Network::Mainnet) < difficulty("37kL", Network::Mainnet)
+    );
+    assert_eq!(
+        true,
+        (difficulty("37kL", Network::Mainnet) / difficulty("37k", Network::Mainnet) - 58.0).abs()
+            < 1e-6
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn vanity_search_finds_account() {
+    use lunesrs::wallet::account::Account;
+
+    let pattern: VanityPattern = "suffix:z".parse().unwrap();
+    let options = VanityOptions {
+        network: Network::Testnet,
+        threads: 2,
+        ..VanityOptions::default()
+    };
+    let found = search_vanity(&pattern, &options, |_| true)
+        .unwrap()
+        .unwrap();
+    let account = &found.account;
+    let again = Account::from_seed(account.seed().unwrap(), 0, Network::Testnet.chain_id());
+
+    assert_eq!(true, account.address_b58().ends_with('z'));
+    assert_eq!(account.network(), Network::Testnet);
+    assert_eq!(again.address(), account.address());
+    assert_eq!(true, found.attempts >= 1);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn vanity_search_stops() {
+    let pattern: VanityPattern = "suffix:zzzzzz".parse().unwrap();
+    let limited = VanityOptions {
+        threads: 1,
+        max_attempts: Some(5),
+        ..VanityOptions::default()
+    };
+    let reported = VanityOptions {
+        threads: 3,
+        progress_interval: 4,
+        ..VanityOptions::default()
+    };
+    let mut progress = vec![];
+
+    assert_eq!(
+        true,
+        search_vanity(&pattern, &limited, |_| true)
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        true,
+        search_vanity(&pattern, &reported, |p| {
+            progress.push(*p);
+            p.attempts < 12
+        })
+        .unwrap()
+        .is_none()
+    );
+    assert_eq!(
+        progress.iter().map(|p| p.attempts).collect::<Vec<u64>>(),
+        [4, 8, 12]
+    );
+    assert_eq!(true, progress[2].probability().unwrap() > 0.0);
+    assert_eq!(
+        true,
+        search_vanity(&"37L".parse().unwrap(), &limited, |_| true).is_err()
+    );
+}
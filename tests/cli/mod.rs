@@ -0,0 +1,221 @@
+use clap::Parser;
+use lunesrs::cli::Cli;
+use lunesrs::error::LunesError;
+use lunesrs::transactions::json::transaction_from_json;
+use serde_json::Value;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+fn run(args: &[&str]) -> Result<Value, LunesError> {
+    Cli::try_parse_from([&["lunes"], args].concat())
+        .unwrap()
+        .run()
+}
+
+#[test]
+fn cli_seed_and_account() {
+    let seed = run(&["seed", "new", "--words", "12"]).unwrap();
+    let derived = run(&["account", "derive", "--seed", SEED, "--nonce", "1"]).unwrap();
+    let testnet = run(&["--network", "testnet", "account", "derive", "--seed", SEED]).unwrap();
+    let private = run(&["account", "derive", "--seed", SEED, "--show-private-key"]).unwrap();
+    let from_key = run(&[
+        "account",
+        "derive",
+        "--private-key",
+        private["privateKey"].as_str().unwrap(),
+    ])
+    .unwrap();
+
+    assert_eq!(seed["seed"].as_str().unwrap().split(' ').count(), 12);
+    assert_eq!(derived["nonce"], 1);
+    assert_eq!(derived.get("privateKey"), None);
+    assert_eq!(testnet["network"], "testnet");
+    assert_eq!(testnet["chainId"], 0);
+    assert_eq!(from_key["address"], "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+    assert_eq!(from_key["nonce"], Value::Null);
+    assert_eq!(
+        true,
+        run(&["account", "derive", "--seed", "scrub guard swimm"]).is_err()
+    );
+    assert_eq!(
+        true,
+        Cli::try_parse_from(["lunes", "account", "derive"]).is_err()
+    );
+}
+
+#[test]
+fn cli_address_validate() {
+    let valid = run(&["address", "validate", "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj"]).unwrap();
+    let other_chain = run(&[
+        "--network",
+        "testnet",
+        "address",
+        "validate",
+        "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj",
+    ])
+    .unwrap();
+    let checksum = run(&["address", "validate", "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aak"]).unwrap();
+
+    assert_eq!(valid["valid"], true);
+    assert_eq!(valid["network"], "mainnet");
+    assert_eq!(other_chain["valid"], false);
+    assert_eq!(checksum["valid"], false);
+    assert_eq!(checksum["error"], "address checksum does not match");
+}
+
+#[test]
+fn cli_sign_and_verify() {
+    let signed = run(&["sign", "--seed", SEED, "Lunes"]).unwrap();
+    let verify = |message: &str, base58: bool| {
+        let mut args = vec![
+            "verify",
+            "--public-key",
+            signed["publicKey"].as_str().unwrap(),
+            "--signature",
+            signed["signature"].as_str().unwrap(),
+            message,
+        ];
+        if base58 {
+            args.push("--base58");
+        }
+        run(&args).unwrap()["valid"].clone()
+    };
+    let encoded = run(&["base58", "encode", "Lunes"]).unwrap();
+
+    assert_eq!(signed["address"], "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj");
+    assert_eq!(verify("Lunes", false), true);
+    assert_eq!(verify("lunes", false), false);
+    assert_eq!(verify(encoded["base58"].as_str().unwrap(), true), true);
+}
+
+#[test]
+fn cli_verify_checks_lengths() {
+    let signed = run(&["sign", "--seed", SEED, "Lunes"]).unwrap();
+    let verify = |public_key: &str, signature: &str| {
+        run(&[
+            "verify",
+            "--public-key",
+            public_key,
+            "--signature",
+            signature,
+            "hi",
+        ])
+    };
+    let public_key = signed["publicKey"].as_str().unwrap();
+    let signature = signed["signature"].as_str().unwrap();
+
+    assert_eq!(
+        verify("2", &"1".repeat(64)),
+        Err(LunesError::InvalidKeyLength(1))
+    );
+    assert_eq!(
+        verify(public_key, "2"),
+        Err(LunesError::InvalidSignatureLength(1))
+    );
+    assert_eq!(verify(public_key, signature).unwrap()["valid"], false);
+}
+
+#[test]
+fn cli_missing_key() {
+    use lunesrs::cli::KeyArgs;
+    use lunesrs::network::Network;
+
+    let key = KeyArgs {
+        seed: None,
+        nonce: 0,
+        private_key: None,
+    };
+
+    assert_eq!(
+        key.account(Network::Mainnet).err(),
+        Some(LunesError::MissingKey)
+    );
+}
+
+#[test]
+fn cli_base58() {
+    let encoded = run(&["base58", "encode", "Lunes"]).unwrap();
+    let decoded = run(&["base58", "decode", encoded["base58"].as_str().unwrap()]).unwrap();
+    let binary = run(&["base58", "decode", "2"]).unwrap();
+
+    assert_eq!(decoded["text"], "Lunes");
+    assert_eq!(
+        decoded["bytes"],
+        serde_json::json!([76, 117, 110, 101, 115])
+    );
+    assert_eq!(binary["bytes"], serde_json::json!([1]));
+    assert_eq!(
+        true,
+        matches!(
+            run(&["base58", "decode", "0OIl"]),
+            Err(LunesError::InvalidBase58(_))
+        )
+    );
+}
+
+#[test]
+fn cli_tx_build() {
+    let transfer = r#"{"type": 4, "recipient": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj", "amount": 100000000, "fee": 100000}"#;
+    let signed = run(&["tx", "build", "--seed", SEED, transfer]).unwrap();
+    let fixed = run(&[
+        "tx",
+        "build",
+        "--seed",
+        SEED,
+        r#"{"type": 9, "leaseId": "11111111111111111111111111111111", "fee": 100000, "timestamp": 1528077600000}"#,
+    ])
+    .unwrap();
+    let no_fee = run(&[
+        "tx",
+        "build",
+        "--seed",
+        SEED,
+        r#"{"type": 4, "recipient": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj", "amount": 1}"#,
+    ])
+    .unwrap();
+    let path = std::env::temp_dir().join("lunesrs-cli-tx-build.json");
+    std::fs::write(&path, transfer).unwrap();
+    let from_file = run(&["tx", "build", "--seed", SEED, path.to_str().unwrap()]).unwrap();
+
+    assert_eq!(signed["type"], 4);
+    assert_eq!(
+        signed["senderPublicKey"],
+        "2uuQVr3B5aGgvSJ5BMCw4Cd19tdYdnMGoYnji99aPde4"
+    );
+    assert_eq!(true, signed["timestamp"].as_u64().unwrap() > 1528077600000);
+    assert_eq!(true, transaction_from_json(signed.to_string(), 1).is_ok());
+    assert_eq!(no_fee["fee"], 100000);
+    assert_eq!(true, transaction_from_json(no_fee.to_string(), 1).is_ok());
+    assert_eq!(fixed["timestamp"], 1528077600000u64);
+    assert_eq!(from_file["recipient"], signed["recipient"]);
+    assert_eq!(
+        true,
+        run(&[
+            "tx",
+            "build",
+            "--seed",
+            SEED,
+            r#"{"type": 4, "senderPublicKey": "4Dy8E9UaZscuPUf2GLxV44RCNL7oxmEXXkgWXaug1WKV", "recipient": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj", "amount": 1, "fee": 100000}"#,
+        ])
+        .is_err()
+    );
+    assert_eq!(
+        true,
+        matches!(
+            run(&["tx", "build", "--seed", SEED, r#"{"type": 2}"#]),
+            Err(LunesError::Json(_))
+        )
+    );
+}
+
+#[test]
+fn cli_tx_build_testnet_alias() {
+    let alias = r#"{"type": 10, "alias": "lunes", "fee": 100000}"#;
+    let signed = run(&["--network", "testnet", "tx", "build", "--seed", SEED, alias]).unwrap();
+    let bytes = transaction_from_json(signed.to_string(), 0).unwrap();
+
+    assert_eq!(signed["alias"], "lunes");
+    assert_eq!(bytes[36], b'0');
+    assert_eq!(true, transaction_from_json(signed.to_string(), 1).is_err());
+}
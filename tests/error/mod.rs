@@ -0,0 +1,34 @@
+use lunesrs::error::LunesError;
+use lunesrs::transactions::TransactionError;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn wrap_transaction_errors() {
+    use std::error::Error;
+
+    let error = LunesError::from(TransactionError::InvalidSignature);
+
+    assert_eq!(
+        error,
+        LunesError::Transaction(TransactionError::InvalidSignature)
+    );
+    assert_eq!(
+        error.to_string(),
+        TransactionError::InvalidSignature.to_string()
+    );
+    assert_eq!(true, error.source().is_some());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn describe_address_errors() {
+    assert_eq!(
+        LunesError::InvalidAddressLength(3).to_string(),
+        "address must have 26 bytes, got 3"
+    );
+    assert_eq!(
+        LunesError::InvalidAddressVersion(2).to_string(),
+        "address version must be one of [1, 11], got 2"
+    );
+}
@@ -1,2 +1,15 @@
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_borrow,
+    clippy::useless_vec
+)]
+
 mod account;
+#[cfg(feature = "cli")]
+mod cli;
+mod error;
+mod network;
+#[cfg(feature = "node")]
+mod node;
+mod transactions;
 mod utils;
@@ -0,0 +1,137 @@
+use lunesrs::network::Network;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SEED: &str =
+    "scrub guard swim catch range upon dawn ensure segment alpha sentence spend effort bar benefit";
+
+#[test]
+#[wasm_bindgen_test]
+fn network_chain_bytes() {
+    let devnet = Network::Custom { byte: b'D' };
+
+    assert_eq!(Network::Mainnet.chain_byte(), b'1');
+    assert_eq!(Network::Testnet.chain_byte(), b'0');
+    assert_eq!(Network::from_chain_byte(b'1'), Network::Mainnet);
+    assert_eq!(Network::from_chain_byte(b'0'), Network::Testnet);
+    assert_eq!(Network::from_chain_byte(b'D'), devnet);
+    assert_eq!(Network::from_chain_id(7), Network::Custom { byte: b'7' });
+    for network in [Network::Mainnet, Network::Testnet, devnet] {
+        assert_eq!(Network::from_chain_id(network.chain_id()), network);
+        assert_eq!(network.to_string().parse(), Ok(network));
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn network_defaults() {
+    use lunesrs::error::LunesError;
+    use lunesrs::network::{chain_id_of, MAINNET_FEE_DEFAULTS};
+
+    assert_eq!(Network::default(), Network::Mainnet);
+    assert_eq!(Network::Mainnet.node_urls(), ["https://lunesnode.lunes.io"]);
+    assert_eq!(true, Network::Custom { byte: b'D' }.node_urls().is_empty());
+    assert_eq!(Network::Testnet.fee_defaults(), MAINNET_FEE_DEFAULTS);
+    assert_eq!(
+        Network::Custom { byte: b'D' }.fee_defaults(),
+        MAINNET_FEE_DEFAULTS
+    );
+    assert_eq!(chain_id_of("Mainnet".to_string()), Ok(1));
+    assert_eq!(chain_id_of("D".to_string()), Ok(b'D'));
+    assert_eq!(
+        chain_id_of("devnet".to_string()),
+        Err(LunesError::InvalidNetwork("devnet".to_string()))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn letter_chain_addresses() {
+    use lunesrs::wallet::account::Account;
+    use lunesrs::wallet::address::Address;
+    use lunesrs::wallet::secret::Seed;
+    use lunesrs::wallet::signatures::try_validate_address;
+
+    let devnet = Network::Custom { byte: b'D' };
+    let account = Account::from_secret_seed(Seed::from(SEED), 0, devnet);
+    let address = Address::from_bytes(account.address()).unwrap();
+
+    assert_eq!(account.network(), devnet);
+    assert_eq!(account.chain_id(), b'D');
+    assert_eq!(address.chain_byte(), b'D');
+    assert_eq!(address.network(), devnet);
+    assert_eq!(
+        Account::from_seed(SEED.to_string(), 0, b'D').address(),
+        account.address()
+    );
+    assert_eq!(try_validate_address(b'D', account.address()), Ok(()));
+    assert_eq!(true, address.check_network(Network::Mainnet).is_err());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn letter_chain_aliases() {
+    use lunesrs::transactions::alias::{Alias, CreateAliasTransaction};
+    use lunesrs::transactions::parser::{parse_transaction, TransactionKind};
+    use lunesrs::transactions::recipient::Recipient;
+    use lunesrs::wallet::assembly::to_public_key;
+
+    let devnet = Network::Custom { byte: b'D' };
+    let alias = Alias::new(devnet, "lunes");
+    let tx = CreateAliasTransaction {
+        sender_public_key: to_public_key(vec![1; 32]),
+        alias: alias.clone(),
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let parsed = parse_transaction(tx.sign(vec![1; 32]).unwrap()).unwrap();
+
+    assert_eq!(alias.to_string(), "alias:D:lunes");
+    assert_eq!(alias.to_bytes().unwrap()[1], b'D');
+    assert_eq!(alias.network(), devnet);
+    assert_eq!(parsed.transaction, TransactionKind::CreateAlias(tx));
+    assert_eq!(
+        true,
+        Recipient::Alias(alias.clone()).validate(devnet).is_ok()
+    );
+    assert_eq!(
+        true,
+        Recipient::Alias(alias).validate(Network::Mainnet).is_err()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn network_custom_fees() {
+    use lunesrs::network::{FeeDefaults, MAINNET_FEE_DEFAULTS};
+    use lunesrs::transactions::fee::{suggest_fee_with, SmartScripts};
+    use lunesrs::transactions::lease::LeaseTransaction;
+    use lunesrs::transactions::parser::{parse_transaction, TransactionKind};
+    use lunesrs::wallet::account::Account;
+
+    let devnet = Network::Custom { byte: b'F' };
+    let fees = FeeDefaults {
+        lease: 7,
+        ..devnet.fee_defaults()
+    };
+    let sender = Account::from_seed(SEED.to_string(), 0, b'F');
+    let recipient = Account::from_seed(SEED.to_string(), 1, b'F');
+
+    let mut tx = LeaseTransaction {
+        sender_public_key: sender.public_key(),
+        recipient: recipient.address_b58().parse().unwrap(),
+        amount: 100000000,
+        fee: 0,
+        timestamp: 1528077600000,
+    };
+    tx.fee = suggest_fee_with(&tx, &fees, SmartScripts::default());
+    let signed = tx.sign(sender.private_key().unwrap(), devnet).unwrap();
+
+    assert_eq!(devnet.fee_defaults(), MAINNET_FEE_DEFAULTS);
+    assert_eq!(
+        true,
+        matches!(
+            parse_transaction(signed).unwrap().transaction,
+            TransactionKind::Lease(tx) if tx.fee == 7
+        )
+    );
+}
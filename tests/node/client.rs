@@ -0,0 +1,225 @@
+use std::cell::RefCell;
+
+use lunesrs::error::LunesError;
+use lunesrs::network::Network;
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::transport::{Method, Request, Response};
+use lunesrs::node::NodeError;
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::transactions::parser::parse_transaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use serde_json::{json, Value};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const NODE: &str = "http://localhost:5555";
+const ADDRESS: &str = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj";
+
+/// Answer every request with the response of its path and remember the requests
+fn stub<'a>(
+    routes: &'a [(&'a str, u16, &'a str)],
+    requests: &'a RefCell<Vec<Request>>,
+) -> impl Fn(&Request) -> Result<Response, NodeError> + 'a {
+    move |request: &Request| {
+        requests.borrow_mut().push(request.clone());
+        routes
+            .iter()
+            .find(|(path, _, _)| request.url == format!("{}{}", NODE, path))
+            .map(|(_, status, body)| Response {
+                status: *status,
+                body: body.to_string(),
+            })
+            .ok_or_else(|| NodeError::Transport(format!("connection refused: {}", request.url)))
+    }
+}
+
+fn lease() -> LeaseTransaction {
+    LeaseTransaction {
+        sender_public_key: to_public_key(to_private_key(vec![1; 32])),
+        recipient: ADDRESS.parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn node_balances() {
+    let balance = format!(
+        r#"{{"address": "{}", "confirmations": 0, "balance": 150000000}}"#,
+        ADDRESS
+    );
+    let assets = format!(
+        r#"{{"address": "{}", "balances": [{{"assetId": "{}", "balance": 42, "reissuable": true, "quantity": 1000, "issueTransaction": null}}]}}"#,
+        ADDRESS, "11111111111111111111111111111111"
+    );
+    let routes = [
+        (
+            "/addresses/balance/37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj",
+            200,
+            balance.as_str(),
+        ),
+        (
+            "/assets/balance/37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj",
+            200,
+            assets.as_str(),
+        ),
+    ];
+    let requests = RefCell::new(vec![]);
+    let client = NodeClient::new(format!("{}/", NODE), stub(&routes, &requests));
+    let address = ADDRESS.parse().unwrap();
+
+    let balance = client.balance(&address).unwrap();
+    let assets = client.asset_balances(&address).unwrap();
+
+    assert_eq!(client.url(), NODE);
+    assert_eq!(balance.address, address);
+    assert_eq!(balance.balance, 150000000);
+    assert_eq!(assets.len(), 1);
+    assert_eq!(assets[0].asset_id, vec![0; 32]);
+    assert_eq!(assets[0].balance, 42);
+    assert_eq!(assets[0].reissuable, true);
+    assert_eq!(
+        requests
+            .borrow()
+            .iter()
+            .map(|r| r.method)
+            .collect::<Vec<Method>>(),
+        [Method::Get, Method::Get]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn node_transactions() {
+    let signed = parse_transaction(
+        lease()
+            .sign(to_private_key(vec![1; 32]), Network::Mainnet)
+            .unwrap(),
+    )
+    .unwrap();
+    let mut confirmed: Value = serde_json::to_value(&signed).unwrap();
+    confirmed["height"] = json!(1200);
+    let confirmed = confirmed.to_string();
+    let unconfirmed = format!(
+        "[{}, {}]",
+        serde_json::to_string(&signed).unwrap(),
+        r#"{"type": 7, "id": "exchange", "timestamp": 1528077600000}"#
+    );
+    let info_path = format!("/transactions/info/{}", signed.id().unwrap());
+    let routes = [
+        (info_path.as_str(), 200, confirmed.as_str()),
+        (
+            "/transactions/info/unknown",
+            404,
+            r#"{"status": "error", "details": "Transaction is not in blockchain"}"#,
+        ),
+        ("/transactions/unconfirmed", 200, unconfirmed.as_str()),
+        ("/blocks/height", 200, r#"{"height": 1201}"#),
+    ];
+    let requests = RefCell::new(vec![]);
+    let client = NodeClient::new(NODE, stub(&routes, &requests));
+
+    let info = client
+        .transaction_info(&signed.id().unwrap())
+        .unwrap()
+        .unwrap();
+    let pool = client.unconfirmed().unwrap();
+
+    assert_eq!(info.id, lease().id().unwrap());
+    assert_eq!(info.type_id, 8);
+    assert_eq!(info.height, Some(1200));
+    assert_eq!(info.transaction(Network::Mainnet), Ok(signed));
+    assert_eq!(client.transaction_info("unknown"), Ok(None));
+    assert_eq!(pool.len(), 2);
+    assert_eq!(pool[0].height, None);
+    assert_eq!(pool[1].type_id, 7);
+    assert_eq!(true, pool[1].transaction(Network::Mainnet).is_err());
+    assert_eq!(client.height(), Ok(1201));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn node_broadcast() {
+    let signed_bytes = lease()
+        .sign(to_private_key(vec![1; 32]), Network::Mainnet)
+        .unwrap();
+    let signed = parse_transaction(signed_bytes.clone()).unwrap();
+    let accepted = serde_json::to_string(&signed).unwrap();
+    let accepting = [("/transactions/broadcast", 200, accepted.as_str())];
+    let rejecting = [(
+        "/transactions/broadcast",
+        400,
+        r#"{"error": 112, "message": "State check failed. Reason: negative balance", "tx": {}}"#,
+    )];
+    let requests = RefCell::new(vec![]);
+
+    let info = NodeClient::new(NODE, stub(&accepting, &requests))
+        .broadcast_bytes(signed_bytes)
+        .unwrap();
+    let rejected = NodeClient::new(NODE, stub(&rejecting, &requests)).broadcast(&signed);
+
+    assert_eq!(info.id, signed.id().unwrap());
+    assert_eq!(info.height, None);
+    assert_eq!(requests.borrow()[0].method, Method::Post);
+    assert_eq!(
+        serde_json::from_str::<Value>(requests.borrow()[0].body.as_ref().unwrap()).unwrap(),
+        serde_json::to_value(&signed).unwrap()
+    );
+    assert_eq!(
+        rejected,
+        Err(LunesError::Node(NodeError::Api {
+            status: 400,
+            code: Some(112),
+            message: "State check failed. Reason: negative balance".to_string(),
+        }))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn node_errors() {
+    let routes = [
+        ("/blocks/height", 200, r#"{"last": 1}"#),
+        ("/transactions/unconfirmed", 502, "Bad Gateway"),
+    ];
+    let requests = RefCell::new(vec![]);
+    let client = NodeClient::new(NODE, stub(&routes, &requests));
+    let devnet = NodeClient::for_network(Network::Custom { byte: b'D' }, stub(&routes, &requests));
+
+    assert_eq!(
+        true,
+        matches!(
+            client.height(),
+            Err(LunesError::Node(NodeError::InvalidResponse(_)))
+        )
+    );
+    assert_eq!(
+        client.unconfirmed(),
+        Err(LunesError::Node(NodeError::Api {
+            status: 502,
+            code: None,
+            message: "Bad Gateway".to_string(),
+        }))
+    );
+    assert_eq!(
+        true,
+        matches!(
+            client.balance(&ADDRESS.parse().unwrap()),
+            Err(LunesError::Node(NodeError::Transport(_)))
+        )
+    );
+    assert_eq!(
+        devnet.err(),
+        Some(LunesError::Node(NodeError::NoNodeUrl(Network::Custom {
+            byte: b'D'
+        })))
+    );
+    assert_eq!(
+        NodeClient::for_network(Network::Testnet, stub(&routes, &requests))
+            .unwrap()
+            .url(),
+        "https://lunesnode-testnet.lunes.io"
+    );
+}
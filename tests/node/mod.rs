@@ -0,0 +1,2 @@
+mod client;
+mod tracker;
@@ -0,0 +1,289 @@
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+use lunesrs::error::LunesError;
+use lunesrs::network::Network;
+use lunesrs::node::client::NodeClient;
+use lunesrs::node::tracker::{Outcome, Tracker, TrackerOptions};
+use lunesrs::node::transport::{Request, Response, Transport};
+use lunesrs::node::NodeError;
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::utils::base58::vec_to_b58;
+use lunesrs::utils::crypto::to_blake2b32b;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const NODE: &str = "http://localhost:5555";
+const INTERVAL: Duration = Duration::from_secs(5);
+
+/// State of the fake node at a poll: its height, and where the transaction is
+#[derive(Clone, Copy)]
+struct Poll {
+    height: u64,
+    in_pool: bool,
+    block: Option<u64>,
+}
+
+const fn pool(height: u64) -> Poll {
+    Poll {
+        height,
+        in_pool: true,
+        block: None,
+    }
+}
+
+const fn block(height: u64, block: u64) -> Poll {
+    Poll {
+        height,
+        in_pool: false,
+        block: Some(block),
+    }
+}
+
+const GONE: Poll = Poll {
+    height: 100,
+    in_pool: false,
+    block: None,
+};
+
+/// Node that moves to the next poll of its script every time the tracker sleeps
+struct FakeNode {
+    id: String,
+    broadcast: Response,
+    script: Vec<Poll>,
+    step: Cell<usize>,
+    slept: Cell<Duration>,
+    paths: RefCell<Vec<String>>,
+}
+
+impl FakeNode {
+    fn new(id: &str, script: Vec<Poll>) -> FakeNode {
+        FakeNode {
+            id: id.to_string(),
+            broadcast: Response::ok(format!(r#"{{"type": 8, "id": "{}"}}"#, id)),
+            script,
+            step: Cell::new(0),
+            slept: Cell::new(Duration::ZERO),
+            paths: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl Transport for FakeNode {
+    fn send(&self, request: &Request) -> Result<Response, NodeError> {
+        let path = request.url.trim_start_matches(NODE).to_string();
+        let poll = self.script[self.step.get().min(self.script.len() - 1)];
+        let tx = |height: Option<u64>| match height {
+            Some(height) => format!(
+                r#"{{"type": 8, "id": "{}", "height": {}}}"#,
+                self.id, height
+            ),
+            None => format!(r#"{{"type": 8, "id": "{}"}}"#, self.id),
+        };
+        self.paths.borrow_mut().push(path.clone());
+
+        Ok(match path.as_str() {
+            "/transactions/broadcast" => self.broadcast.clone(),
+            "/transactions/unconfirmed" if poll.in_pool => Response::ok(format!("[{}]", tx(None))),
+            "/transactions/unconfirmed" => Response::ok("[]"),
+            "/blocks/height" => Response::ok(format!(r#"{{"height": {}}}"#, poll.height)),
+            info if info == format!("/transactions/info/{}", self.id) && poll.block.is_some() => {
+                Response::ok(tx(poll.block))
+            }
+            _ => Response {
+                status: 404,
+                body: r#"{"status": "error", "details": "Transaction is not in blockchain"}"#
+                    .to_string(),
+            },
+        })
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.step.set(self.step.get() + 1);
+        self.slept.set(self.slept.get() + duration);
+    }
+}
+
+fn signed() -> (String, Vec<u8>) {
+    let prvk = to_private_key(vec![1; 32]);
+    let tx = LeaseTransaction {
+        sender_public_key: to_public_key(prvk.clone()),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    (
+        vec_to_b58(to_blake2b32b(tx.body_bytes().unwrap())),
+        tx.sign(prvk, Network::Mainnet).unwrap(),
+    )
+}
+
+fn options(confirmations: u64, polls: u32) -> TrackerOptions {
+    TrackerOptions {
+        confirmations,
+        interval: INTERVAL,
+        timeout: INTERVAL * polls,
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_confirms() {
+    let (id, signed) = signed();
+    let node = FakeNode::new(
+        &id,
+        vec![
+            pool(99),
+            pool(99),
+            block(100, 100),
+            block(101, 100),
+            block(102, 100),
+        ],
+    );
+    let client = NodeClient::new(NODE, node);
+
+    let outcome = Tracker::new(&client, options(3, 10))
+        .broadcast_bytes(signed)
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        Outcome::Confirmed {
+            id: id.clone(),
+            height: 100,
+            confirmations: 3
+        }
+    );
+    assert_eq!(outcome.id(), id);
+    assert_eq!(true, outcome.is_confirmed());
+    assert_eq!(client.transport().slept.get(), INTERVAL * 4);
+    assert_eq!(
+        client.transport().paths.borrow()[0],
+        "/transactions/broadcast"
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_rejected() {
+    let (id, signed) = signed();
+    let mut node = FakeNode::new(&id, vec![pool(99)]);
+    node.broadcast = Response {
+        status: 400,
+        body: r#"{"error": 112, "message": "State check failed. Reason: negative balance"}"#
+            .to_string(),
+    };
+    let client = NodeClient::new(NODE, node);
+
+    let outcome = Tracker::new(&client, options(1, 10))
+        .broadcast_bytes(signed)
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        Outcome::Rejected {
+            id,
+            code: Some(112),
+            message: "State check failed. Reason: negative balance".to_string(),
+        }
+    );
+    assert_eq!(false, outcome.is_confirmed());
+    assert_eq!(client.transport().paths.borrow().len(), 1);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_server_error_is_not_rejected() {
+    let (id, signed) = signed();
+    let mut node = FakeNode::new(&id, vec![pool(99)]);
+    node.broadcast = Response {
+        status: 502,
+        body: "Bad Gateway".to_string(),
+    };
+    let client = NodeClient::new(NODE, node);
+
+    let result = Tracker::new(&client, options(1, 10)).broadcast_bytes(signed);
+
+    assert_eq!(
+        true,
+        matches!(
+            result,
+            Err(LunesError::Node(NodeError::Api { status: 502, .. }))
+        )
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_expired() {
+    let (id, signed) = signed();
+    let client = NodeClient::new(NODE, FakeNode::new(&id, vec![pool(99), pool(99), GONE]));
+
+    let outcome = Tracker::new(&client, options(1, 10))
+        .broadcast_bytes(signed)
+        .unwrap();
+
+    assert_eq!(outcome, Outcome::Expired { id });
+    assert_eq!(client.transport().slept.get(), INTERVAL * 2);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_timed_out() {
+    let (id, signed) = signed();
+    let pending = NodeClient::new(NODE, FakeNode::new(&id, vec![pool(99)]));
+    let unconfirmed = NodeClient::new(NODE, FakeNode::new(&id, vec![block(100, 100)]));
+
+    let pending_outcome = Tracker::new(&pending, options(1, 3))
+        .broadcast_bytes(signed.clone())
+        .unwrap();
+    let unconfirmed_outcome = Tracker::new(&unconfirmed, options(2, 3)).wait(&id).unwrap();
+
+    assert_eq!(
+        pending_outcome,
+        Outcome::TimedOut {
+            id: id.clone(),
+            height: None
+        }
+    );
+    assert_eq!(pending.transport().slept.get(), INTERVAL * 3);
+    assert_eq!(
+        unconfirmed_outcome,
+        Outcome::TimedOut {
+            id,
+            height: Some(100)
+        }
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn tracker_errors() {
+    let (id, signed) = signed();
+    let other = NodeClient::new(NODE, FakeNode::new("other", vec![pool(99)]));
+    let down = NodeClient::new(NODE, |_: &Request| -> Result<Response, NodeError> {
+        Err(NodeError::Transport("connection refused".to_string()))
+    });
+
+    assert_eq!(
+        true,
+        matches!(
+            Tracker::new(&other, options(1, 3)).broadcast_bytes(signed.clone()),
+            Err(LunesError::Node(NodeError::InvalidResponse(_)))
+        )
+    );
+    assert_eq!(
+        Tracker::new(&down, options(1, 3)).wait(&id),
+        Err(LunesError::Node(NodeError::Transport(
+            "connection refused".to_string()
+        )))
+    );
+    assert_eq!(
+        true,
+        Tracker::new(&down, options(1, 3))
+            .broadcast_bytes(signed[1..].to_vec())
+            .is_err()
+    );
+}
@@ -0,0 +1,113 @@
+use lunesrs::network::Network;
+use lunesrs::transactions::alias::{
+    sign_create_alias, sign_create_alias_js, Alias, CreateAliasTransaction,
+};
+use lunesrs::transactions::Transaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn alias(name: &str) -> Alias {
+    Alias {
+        chain_id: 1,
+        name: name.to_string(),
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn alias_validation() {
+    for name in ["lunes", "lunes-platform.io", "@_0123456789", "abcd"] {
+        assert_eq!(alias(name).validate(), Ok(()));
+    }
+    for name in ["lun", "Lunes", "lunes platform", "lunes!", &"a".repeat(31)] {
+        assert_eq!(
+            alias(name).validate(),
+            Err(TransactionError::InvalidAlias(name.to_string()))
+        );
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn alias_bytes() {
+    let testnet = Alias {
+        chain_id: 0,
+        name: "lunes".to_string(),
+    };
+
+    assert_eq!(
+        alias("lunes").to_bytes(),
+        Ok(vec![2, 49, 0, 5, 108, 117, 110, 101, 115])
+    );
+    assert_eq!(
+        testnet.to_bytes(),
+        Ok(vec![2, 48, 0, 5, 108, 117, 110, 101, 115])
+    );
+    assert_eq!(alias("lunes").to_string(), "alias:1:lunes");
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn create_alias_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = CreateAliasTransaction {
+        sender_public_key: pubk.clone(),
+        alias: alias("lunes"),
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk.clone()).unwrap();
+    let exported = sign_create_alias(
+        prvk.clone(),
+        Network::Mainnet,
+        pubk.clone(),
+        "lunes".to_string(),
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(
+        body,
+        [
+            vec![10],
+            pubk.clone(),
+            vec![0, 9, 2, 49, 0, 5, 108, 117, 110, 101, 115],
+            vec![0, 0, 0, 0, 0, 1, 134, 160],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+        ]
+        .concat()
+    );
+    let testnet = CreateAliasTransaction {
+        alias: Alias::new(Network::Testnet, "lunes"),
+        ..tx.clone()
+    };
+    let exported_js = sign_create_alias_js(
+        prvk.clone(),
+        Network::Testnet.chain_id(),
+        pubk.clone(),
+        "lunes".to_string(),
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(exported[..body.len()], body);
+    assert_eq!(exported_js[..body.len()], testnet.body_bytes().unwrap());
+    assert_eq!(
+        true,
+        validate_signature(pubk, body.clone(), signed[body.len()..].to_vec())
+    );
+    assert_eq!(
+        CreateAliasTransaction {
+            alias: alias("Lunes"),
+            ..tx
+        }
+        .sign(prvk),
+        Err(TransactionError::InvalidAlias("Lunes".to_string()))
+    );
+}
@@ -0,0 +1,79 @@
+use lunesrs::error::LunesError;
+use lunesrs::transactions::burn::{sign_burn, BurnTransaction};
+use lunesrs::transactions::{Transaction, TransactionError};
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn burn(sender_public_key: Vec<u8>) -> BurnTransaction {
+    BurnTransaction {
+        sender_public_key,
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn burn_body_bytes() {
+    assert_eq!(
+        burn(vec![7; 32]).body_bytes().unwrap(),
+        [
+            vec![6],
+            vec![7; 32],
+            vec![2; 32],
+            vec![0, 0, 0, 0, 0, 0, 3, 232],
+            vec![0, 0, 0, 0, 0, 1, 134, 160],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn burn_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = burn(pubk.clone());
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk.clone()).unwrap();
+    let exported = sign_burn(
+        prvk,
+        pubk.clone(),
+        tx.asset_id.clone(),
+        tx.amount,
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(signed[..body.len()], body);
+    assert_eq!(exported[..body.len()], body);
+    assert_eq!(
+        true,
+        validate_signature(pubk, body.clone(), signed[body.len()..].to_vec())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn burn_invalid_asset_id() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = BurnTransaction {
+        asset_id: vec![2; 33],
+        ..burn(pubk.clone())
+    };
+
+    assert_eq!(
+        tx.sign(prvk.clone()),
+        Err(TransactionError::InvalidAssetId(33))
+    );
+    assert_eq!(
+        sign_burn(prvk, pubk, vec![], 1000, 100000, 1528077600000),
+        Err(LunesError::Transaction(TransactionError::InvalidAssetId(0)))
+    );
+}
@@ -0,0 +1,169 @@
+use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::validation::{Rules, Validate};
+use lunesrs::transactions::Transaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn entry(key: &str, value: DataValue) -> DataEntry {
+    DataEntry {
+        key: key.to_string(),
+        value,
+    }
+}
+
+fn data(sender_public_key: Vec<u8>, entries: Vec<DataEntry>) -> DataTransaction {
+    DataTransaction {
+        sender_public_key,
+        entries,
+        fee: 100000,
+        timestamp: 1528077600000,
+    }
+}
+
+fn all_types() -> Vec<DataEntry> {
+    vec![
+        entry("int", DataValue::Integer(-1)),
+        entry("bool", DataValue::Boolean(true)),
+        entry("bin", DataValue::Binary(vec![1, 2, 3])),
+        entry("str", DataValue::String("Lunes".to_string())),
+    ]
+}
+
+fn rules(chain_id: u8) -> Rules {
+    Rules::new(chain_id, 1528077600000)
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn data_body_bytes() {
+    assert_eq!(
+        data(vec![7; 32], all_types()).body_bytes().unwrap(),
+        [
+            vec![12, 1],
+            vec![7; 32],
+            vec![0, 4],
+            vec![0, 3, 105, 110, 116, 0, 255, 255, 255, 255, 255, 255, 255, 255],
+            vec![0, 4, 98, 111, 111, 108, 1, 1],
+            vec![0, 3, 98, 105, 110, 2, 0, 3, 1, 2, 3],
+            vec![0, 3, 115, 116, 114, 3, 0, 5, 76, 117, 110, 101, 115],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+            vec![0, 0, 0, 0, 0, 1, 134, 160],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn data_round_trip() {
+    let tx = data(vec![7; 32], all_types());
+
+    assert_eq!(
+        DataTransaction::from_body_bytes(tx.body_bytes().unwrap()),
+        Ok(tx)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn data_parse_errors() {
+    let body = data(vec![7; 32], all_types()).body_bytes().unwrap();
+    let mut unknown_type = body.clone();
+    unknown_type[41] = 9;
+    let mut wrong_boolean = body.clone();
+    wrong_boolean[57] = 2;
+
+    assert_eq!(
+        DataTransaction::from_body_bytes(body[..50].to_vec()),
+        Err(TransactionError::UnexpectedEnd(50))
+    );
+    assert_eq!(
+        DataTransaction::from_body_bytes([body.clone(), vec![0]].concat()),
+        Err(TransactionError::TrailingBytes(body.len()))
+    );
+    assert_eq!(
+        DataTransaction::from_body_bytes([vec![4], body[1..].to_vec()].concat()),
+        Err(TransactionError::InvalidType(4))
+    );
+    assert_eq!(
+        DataTransaction::from_body_bytes([vec![12, 2], body[2..].to_vec()].concat()),
+        Err(TransactionError::InvalidVersion(2))
+    );
+    assert_eq!(
+        DataTransaction::from_body_bytes(unknown_type),
+        Err(TransactionError::InvalidDataType(9))
+    );
+    assert_eq!(
+        DataTransaction::from_body_bytes(wrong_boolean),
+        Err(TransactionError::InvalidBoolean(2))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn data_validation() {
+    let long_key = "k".repeat(101);
+    let too_many = (0..101)
+        .map(|x| entry(&x.to_string(), DataValue::Boolean(true)))
+        .collect();
+    let too_large = (0..10)
+        .map(|x| entry(&x.to_string(), DataValue::Binary(vec![0; 32767])))
+        .collect();
+
+    assert_eq!(data(vec![7; 32], all_types()).violations(&rules(1)), []);
+    assert_eq!(
+        data(vec![7; 32], too_many).violations(&rules(1)),
+        [TransactionError::TooManyEntries(101)]
+    );
+    assert_eq!(
+        data(vec![7; 32], vec![entry("", DataValue::Integer(1))]).violations(&rules(1)),
+        [TransactionError::InvalidKeyLength("".to_string())]
+    );
+    assert_eq!(
+        data(vec![7; 32], vec![entry(&long_key, DataValue::Integer(1))]).violations(&rules(1)),
+        [TransactionError::InvalidKeyLength(long_key)]
+    );
+    assert_eq!(
+        data(
+            vec![7; 32],
+            vec![entry("bin", DataValue::Binary(vec![0; 32768]))]
+        )
+        .violations(&rules(1)),
+        [TransactionError::ValueTooLong("bin".to_string())]
+    );
+    assert_eq!(
+        data(
+            vec![7; 32],
+            vec![entry("str", DataValue::String("s".repeat(70000)))]
+        )
+        .violations(&rules(1)),
+        [TransactionError::ValueTooLong("str".to_string())]
+    );
+    assert_eq!(
+        data(vec![7; 32], [all_types(), all_types()].concat()).violations(&rules(1)),
+        ["int", "bool", "bin", "str"].map(|key| TransactionError::DuplicateKey(key.to_string()))
+    );
+    assert_eq!(
+        data(vec![7; 32], too_large).violations(&rules(1)),
+        [TransactionError::TransactionTooLarge(327782)]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn data_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = data(pubk.clone(), all_types());
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk).unwrap();
+
+    assert_eq!(signed[..body.len()], body);
+    assert_eq!(signed[body.len()..body.len() + 5], [1, 0, 1, 0, 64]);
+    assert_eq!(
+        true,
+        validate_signature(pubk, body.clone(), signed[body.len() + 5..].to_vec())
+    );
+}
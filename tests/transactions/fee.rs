@@ -0,0 +1,182 @@
+use lunesrs::network::{FeeDefaults, Network};
+use lunesrs::transactions::burn::{sign_burn, BurnTransaction};
+use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::fee::{suggest_fee, suggest_fee_with, Fee, SmartScripts};
+use lunesrs::transactions::issue::IssueTransaction;
+use lunesrs::transactions::lease::{sign_lease, LeaseCancelTransaction};
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+use lunesrs::transactions::parser::{parse_transaction, TransactionKind};
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::Transaction;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn transfer() -> TransferTransaction {
+    TransferTransaction {
+        sender_public_key: vec![7; 32],
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: None,
+        fee_asset: None,
+        amount: 100000000,
+        fee: 0,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    }
+}
+
+fn mass_transfer(count: usize) -> MassTransferTransaction {
+    MassTransferTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: None,
+        transfers: vec![
+            Transfer {
+                recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+                amount: 1000,
+            };
+            count
+        ],
+        fee: 0,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    }
+}
+
+fn data(binary: usize) -> DataTransaction {
+    DataTransaction {
+        sender_public_key: vec![7; 32],
+        entries: vec![DataEntry {
+            key: "bin".to_string(),
+            value: DataValue::Binary(vec![0; binary]),
+        }],
+        fee: 0,
+        timestamp: 1528077600000,
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_of_every_type() {
+    let issue = IssueTransaction {
+        sender_public_key: vec![7; 32],
+        name: "Lunes".to_string(),
+        description: String::new(),
+        quantity: 1000,
+        decimals: 8,
+        reissuable: false,
+        fee: 0,
+        timestamp: 1528077600000,
+    };
+    let cancel = LeaseCancelTransaction {
+        sender_public_key: vec![7; 32],
+        lease_id: vec![1; 32],
+        fee: 0,
+        timestamp: 1528077600000,
+    };
+    let burn = BurnTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: vec![1; 32],
+        amount: 1,
+        fee: 5,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(suggest_fee(&transfer()), 100000);
+    assert_eq!(suggest_fee(&issue), 100000000);
+    assert_eq!(suggest_fee(&cancel), 100000);
+    assert_eq!(suggest_fee(&burn), 100000);
+    assert_eq!(suggest_fee(&TransactionKind::Issue(issue)), 100000000);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_of_mass_transfer() {
+    assert_eq!(suggest_fee(&mass_transfer(0)), 100000);
+    assert_eq!(suggest_fee(&mass_transfer(1)), 150000);
+    assert_eq!(suggest_fee(&mass_transfer(10)), 600000);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_of_data_per_kb() {
+    let full = data(895);
+    let over = data(896);
+
+    assert_eq!(full.signed_bytes(vec![0; 64]).unwrap().len(), 1024);
+    assert_eq!(suggest_fee(&data(0)), 100000);
+    assert_eq!(suggest_fee(&full), 100000);
+    assert_eq!(suggest_fee(&over), 200000);
+    assert_eq!(suggest_fee(&data(4000)), 500000);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_with_smart_scripts() {
+    let fees = Network::Mainnet.fee_defaults();
+    let account = SmartScripts {
+        account: true,
+        assets: 0,
+    };
+    let assets = SmartScripts {
+        account: false,
+        assets: 2,
+    };
+
+    assert_eq!(SmartScripts::default().extra_fee(&fees), 0);
+    assert_eq!(suggest_fee_with(&transfer(), &fees, account), 500000);
+    assert_eq!(suggest_fee_with(&transfer(), &fees, assets), 900000);
+    assert_eq!(suggest_fee_with(&mass_transfer(2), &fees, account), 600000);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_with_custom_defaults() {
+    let devnet = FeeDefaults {
+        transfer: 1,
+        mass_transfer: 10,
+        mass_transfer_per_transfer: 2,
+        ..Network::Custom { byte: b'D' }.fee_defaults()
+    };
+
+    assert_eq!(transfer().required_fee(&devnet), 1);
+    assert_eq!(mass_transfer(3).required_fee(&devnet), 16);
+    assert_eq!(
+        mass_transfer(3).required_fee(&FeeDefaults {
+            mass_transfer_per_transfer: u64::MAX,
+            ..devnet
+        }),
+        u64::MAX
+    );
+    assert_eq!(
+        suggest_fee_with(&transfer(), &devnet, SmartScripts::default()),
+        1
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn fee_zero_in_builders() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let burn = sign_burn(prvk.clone(), pubk.clone(), vec![1; 32], 1, 0, 1528077600000).unwrap();
+    let lease = sign_lease(
+        prvk.clone(),
+        Network::Mainnet,
+        pubk.clone(),
+        "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string(),
+        100000000,
+        0,
+        1528077600000,
+    )
+    .unwrap();
+    let fixed = sign_burn(prvk, pubk, vec![1; 32], 1, 300000, 1528077600000).unwrap();
+
+    let fee_of = |signed: Vec<u8>| match parse_transaction(signed).unwrap().transaction {
+        TransactionKind::Burn(tx) => tx.fee,
+        TransactionKind::Lease(tx) => tx.fee,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(fee_of(burn), 100000);
+    assert_eq!(fee_of(lease), 100000);
+    assert_eq!(fee_of(fixed), 300000);
+}
@@ -0,0 +1,116 @@
+use lunesrs::network::Network;
+use lunesrs::transactions::burn::BurnTransaction;
+use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::issue::IssueTransaction;
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+use lunesrs::transactions::{
+    body_from_signed_bytes, transaction_id, Transaction, TransactionError,
+};
+use lunesrs::utils::base58::vec_to_b58;
+use lunesrs::utils::crypto::to_blake2b32b;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn id_of_body_bytes() {
+    let tx = BurnTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(
+        tx.id_bytes().unwrap(),
+        to_blake2b32b(tx.body_bytes().unwrap())
+    );
+    assert_eq!(
+        tx.id().unwrap(),
+        vec_to_b58(to_blake2b32b(tx.body_bytes().unwrap()))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn id_of_signed_bytes() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let issue = IssueTransaction {
+        sender_public_key: pubk.clone(),
+        name: "Lunes".to_string(),
+        description: "".to_string(),
+        quantity: 1000,
+        decimals: 2,
+        reissuable: true,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    };
+    let lease = LeaseTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let mass_transfer = MassTransferTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: None,
+        transfers: vec![Transfer {
+            recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+            amount: 1,
+        }],
+        fee: 150000,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    };
+    let data = DataTransaction {
+        sender_public_key: pubk,
+        entries: vec![DataEntry {
+            key: "score".to_string(),
+            value: DataValue::Integer(10),
+        }],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    assert_eq!(
+        transaction_id(issue.sign(prvk.clone()).unwrap()).unwrap(),
+        issue.id().unwrap()
+    );
+    assert_eq!(
+        transaction_id(lease.sign(prvk.clone(), Network::Mainnet).unwrap()).unwrap(),
+        lease.id().unwrap()
+    );
+    assert_eq!(
+        transaction_id(mass_transfer.sign(prvk.clone(), Network::Mainnet).unwrap()).unwrap(),
+        mass_transfer.id().unwrap()
+    );
+    assert_eq!(
+        transaction_id(data.sign(prvk).unwrap()).unwrap(),
+        data.id().unwrap()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn body_of_invalid_signed_bytes() {
+    assert_eq!(
+        body_from_signed_bytes(vec![]),
+        Err(TransactionError::UnexpectedEnd(0))
+    );
+    assert_eq!(
+        body_from_signed_bytes(vec![4; 65]),
+        Err(TransactionError::UnexpectedEnd(65))
+    );
+    assert_eq!(
+        body_from_signed_bytes(vec![8; 64]),
+        Err(TransactionError::UnexpectedEnd(64))
+    );
+    assert_eq!(
+        body_from_signed_bytes(vec![7; 100]),
+        Err(TransactionError::InvalidType(7))
+    );
+}
@@ -0,0 +1,126 @@
+use lunesrs::transactions::issue::{sign_issue, IssueTransaction};
+use lunesrs::transactions::validation::{Rules, Validate};
+use lunesrs::transactions::Transaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn issue(sender_public_key: Vec<u8>) -> IssueTransaction {
+    IssueTransaction {
+        sender_public_key,
+        name: "Lunes".to_string(),
+        description: "".to_string(),
+        quantity: 1000,
+        decimals: 2,
+        reissuable: true,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    }
+}
+
+fn rules(chain_id: u8) -> Rules {
+    Rules::new(chain_id, 1528077600000)
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn issue_body_bytes() {
+    assert_eq!(
+        issue(vec![7; 32]).body_bytes().unwrap(),
+        [
+            vec![3],
+            vec![7; 32],
+            vec![0, 5, 76, 117, 110, 101, 115],
+            vec![0, 0],
+            vec![0, 0, 0, 0, 0, 0, 3, 232],
+            vec![2],
+            vec![1],
+            vec![0, 0, 0, 0, 5, 245, 225, 0],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn issue_validation() {
+    let tx = issue(vec![7; 32]);
+
+    assert_eq!(tx.violations(&rules(1)), []);
+    assert_eq!(
+        IssueTransaction {
+            name: "Lun".to_string(),
+            ..tx.clone()
+        }
+        .violations(&rules(1)),
+        [TransactionError::InvalidNameLength(3)]
+    );
+    assert_eq!(
+        IssueTransaction {
+            name: "Lunes Platform Token".to_string(),
+            ..tx.clone()
+        }
+        .violations(&rules(1)),
+        [TransactionError::InvalidNameLength(20)]
+    );
+    assert_eq!(
+        IssueTransaction {
+            description: "x".repeat(1001),
+            ..tx.clone()
+        }
+        .violations(&rules(1)),
+        [TransactionError::InvalidDescriptionLength(1001)]
+    );
+    assert_eq!(
+        IssueTransaction {
+            decimals: 9,
+            ..tx.clone()
+        }
+        .violations(&rules(1)),
+        [TransactionError::InvalidDecimals(9)]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn issue_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = issue(pubk.clone());
+    let signed = tx.sign(prvk.clone()).unwrap();
+
+    assert_eq!(signed[0], 3);
+    assert_eq!(signed[65..], tx.body_bytes().unwrap());
+    assert_eq!(
+        true,
+        validate_signature(pubk, tx.body_bytes().unwrap(), signed[1..65].to_vec())
+    );
+    assert_eq!(
+        IssueTransaction { decimals: 9, ..tx }.sign(prvk),
+        Err(TransactionError::InvalidDecimals(9))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn issue_sign_export() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = issue(pubk.clone());
+    let signed = sign_issue(
+        prvk,
+        pubk,
+        tx.name.clone(),
+        tx.description.clone(),
+        tx.quantity,
+        tx.decimals,
+        tx.reissuable,
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(signed[65..], tx.body_bytes().unwrap());
+}
@@ -0,0 +1,284 @@
+use lunesrs::error::LunesError;
+use lunesrs::network::Network;
+use lunesrs::transactions::alias::{Alias, CreateAliasTransaction};
+use lunesrs::transactions::data::{sign_data, DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::json::{transaction_from_json, transaction_to_json};
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+use lunesrs::transactions::parser::{parse_transaction, SignedTransaction, TransactionKind};
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::{Transaction, TransactionError};
+use lunesrs::utils::base58::vec_to_b58;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use serde_json::{json, Value};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn keys() -> (Vec<u8>, Vec<u8>) {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    (prvk, pubk)
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_in_the_node_shape() {
+    let (prvk, pubk) = keys();
+    let tx = TransferTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: Some(vec![2; 32]),
+        fee_asset: None,
+        amount: 100000000,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: "Lunes".as_bytes().to_vec(),
+    };
+    let signed = tx.sign(prvk).unwrap();
+    let json: Value = serde_json::from_str(&transaction_to_json(signed.clone()).unwrap()).unwrap();
+
+    assert_eq!(
+        json,
+        json!({
+            "type": 4,
+            "id": tx.id().unwrap(),
+            "senderPublicKey": vec_to_b58(pubk),
+            "recipient": "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj",
+            "assetId": vec_to_b58(vec![2; 32]),
+            "feeAssetId": null,
+            "amount": 100000000,
+            "fee": 1000000,
+            "timestamp": 1528077600000u64,
+            "attachment": vec_to_b58("Lunes".as_bytes().to_vec()),
+            "signature": vec_to_b58(signed[1..65].to_vec()),
+        })
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn proofs_and_version_of_mass_transfer() {
+    let (prvk, pubk) = keys();
+    let tx = MassTransferTransaction {
+        sender_public_key: pubk,
+        asset_id: None,
+        transfers: vec![Transfer {
+            recipient: "alias:1:lunes".parse().unwrap(),
+            amount: 1000,
+        }],
+        fee: 150000,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    };
+    let signed = tx.sign(prvk, Network::Mainnet).unwrap();
+    let json: Value = serde_json::from_str(&transaction_to_json(signed.clone()).unwrap()).unwrap();
+
+    assert_eq!(json["type"], 11);
+    assert_eq!(json["version"], 1);
+    assert_eq!(
+        json["transfers"],
+        json!([{"recipient": "alias:1:lunes", "amount": 1000}])
+    );
+    assert_eq!(
+        json["proofs"],
+        json!([vec_to_b58(signed[signed.len() - 64..].to_vec())])
+    );
+    assert_eq!(json.get("signature"), None);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn typed_data_entries() {
+    let (_, pubk) = keys();
+    let tx = TransactionKind::Data(DataTransaction {
+        sender_public_key: pubk,
+        entries: vec![
+            DataEntry {
+                key: "int".to_string(),
+                value: DataValue::Integer(-1),
+            },
+            DataEntry {
+                key: "bin".to_string(),
+                value: DataValue::Binary(vec![1, 2, 3]),
+            },
+        ],
+        fee: 100000,
+        timestamp: 1528077600000,
+    });
+    let json = serde_json::to_value(&tx).unwrap();
+
+    assert_eq!(
+        json["data"],
+        json!([
+            {"key": "int", "type": "integer", "value": -1},
+            {"key": "bin", "type": "binary", "value": "base64:AQID"},
+        ])
+    );
+    assert_eq!(serde_json::from_value::<TransactionKind>(json).unwrap(), tx);
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn alias_name_and_chain() {
+    let (prvk, pubk) = keys();
+    let tx = CreateAliasTransaction {
+        sender_public_key: pubk,
+        alias: Alias {
+            chain_id: 0,
+            name: "lunes".to_string(),
+        },
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = parse_transaction(tx.sign(prvk).unwrap()).unwrap();
+    let mut json = serde_json::to_value(&signed).unwrap();
+
+    assert_eq!(json["alias"], "lunes");
+    assert_eq!(json.get("chainId"), None);
+
+    json["chainId"] = 0.into();
+    assert_eq!(
+        serde_json::from_value::<SignedTransaction>(json.clone()).unwrap(),
+        signed
+    );
+
+    json.as_object_mut().unwrap().remove("chainId");
+    assert_eq!(
+        SignedTransaction::from_json(json.clone(), Network::Testnet).unwrap(),
+        signed
+    );
+    let mainnet: SignedTransaction = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        mainnet.transaction,
+        TransactionKind::CreateAlias(CreateAliasTransaction {
+            alias: Alias {
+                chain_id: 1,
+                name: "lunes".to_string()
+            },
+            ..tx
+        })
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn round_trip_from_json() {
+    let (prvk, pubk) = keys();
+    let tx = TransferTransaction {
+        sender_public_key: pubk,
+        recipient: "alias:1:lunes".parse().unwrap(),
+        asset_id: None,
+        fee_asset: Some(vec![3; 32]),
+        amount: 1,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: vec![],
+    };
+    let signed = tx.sign(prvk).unwrap();
+
+    assert_eq!(
+        transaction_from_json(transaction_to_json(signed.clone()).unwrap(), 1).unwrap(),
+        signed
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn round_trip_testnet_alias() {
+    let (prvk, pubk) = keys();
+    let tx = CreateAliasTransaction {
+        sender_public_key: pubk,
+        alias: Alias::new(Network::Testnet, "lunes"),
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = tx.sign(prvk).unwrap();
+    let json = transaction_to_json(signed.clone()).unwrap();
+
+    assert_eq!(
+        transaction_from_json(json.clone(), Network::Testnet.chain_id()).unwrap(),
+        signed
+    );
+    assert_eq!(
+        transaction_from_json(json, Network::Mainnet.chain_id()),
+        Err(LunesError::from(TransactionError::InvalidSignature))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn reject_malformed_json() {
+    let unknown_type = json!({"type": 7, "signature": "1"});
+    let missing_signature = json!({"type": 6});
+    let many_proofs = json!({"type": 12, "proofs": ["1", "1"]});
+    let wrong_version = json!({"type": 12, "version": 2, "proofs": ["1"]});
+
+    assert_eq!(
+        true,
+        serde_json::from_value::<SignedTransaction>(unknown_type).is_err()
+    );
+    assert_eq!(
+        true,
+        serde_json::from_value::<SignedTransaction>(missing_signature).is_err()
+    );
+    assert_eq!(
+        true,
+        serde_json::from_value::<SignedTransaction>(many_proofs).is_err()
+    );
+    assert_eq!(
+        true,
+        serde_json::from_value::<SignedTransaction>(wrong_version).is_err()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn sign_data_from_json_entries() {
+    let (prvk, pubk) = keys();
+    let entries = json!([
+        {"key": "int", "type": "integer", "value": -1},
+        {"key": "bin", "type": "binary", "value": "base64:AQID"},
+    ])
+    .to_string();
+    let tx = DataTransaction {
+        sender_public_key: pubk.clone(),
+        entries: vec![
+            DataEntry {
+                key: "int".to_string(),
+                value: DataValue::Integer(-1),
+            },
+            DataEntry {
+                key: "bin".to_string(),
+                value: DataValue::Binary(vec![1, 2, 3]),
+            },
+        ],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let signed = sign_data(prvk.clone(), pubk.clone(), entries, 100000, 1528077600000).unwrap();
+
+    assert_eq!(true, signed.starts_with(&tx.body_bytes().unwrap()));
+    assert_eq!(
+        true,
+        matches!(
+            sign_data(
+                prvk.clone(),
+                pubk.clone(),
+                "[{".to_string(),
+                0,
+                1528077600000
+            ),
+            Err(LunesError::Json(_))
+        )
+    );
+    assert_eq!(
+        sign_data(
+            prvk,
+            pubk,
+            json!([{"key": "", "type": "integer", "value": 1}]).to_string(),
+            0,
+            1528077600000
+        ),
+        Err(LunesError::from(TransactionError::InvalidKeyLength(
+            String::new()
+        )))
+    );
+}
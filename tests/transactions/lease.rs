@@ -0,0 +1,168 @@
+use lunesrs::network::Network;
+use lunesrs::transactions::lease::{
+    sign_lease, sign_lease_cancel, sign_lease_js, LeaseCancelTransaction, LeaseTransaction,
+};
+use lunesrs::transactions::recipient::Recipient;
+use lunesrs::transactions::validation::{Rules, Validate};
+use lunesrs::transactions::Transaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::utils::base58::b58_to_vec;
+use lunesrs::utils::crypto::to_blake2b32b;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn lease(sender_public_key: Vec<u8>) -> LeaseTransaction {
+    LeaseTransaction {
+        sender_public_key,
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    }
+}
+
+fn rules(chain_id: u8) -> Rules {
+    Rules::new(chain_id, 1528077600000)
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn lease_body_bytes() {
+    let tx = lease(vec![7; 32]);
+
+    assert_eq!(
+        tx.body_bytes().unwrap(),
+        [
+            vec![8],
+            vec![7; 32],
+            b58_to_vec("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string()),
+            vec![0, 0, 0, 0, 5, 245, 225, 0],
+            vec![0, 0, 0, 0, 0, 1, 134, 160],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+        ]
+        .concat()
+    );
+    assert_eq!(
+        tx.id_bytes().unwrap(),
+        to_blake2b32b(tx.body_bytes().unwrap())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn lease_validation() {
+    let mainnet = 1;
+    let testnet = 0;
+    let tx = lease(vec![7; 32]);
+    let short = LeaseTransaction {
+        recipient: Recipient::Address(vec![1, 49]),
+        ..tx.clone()
+    };
+    let alias = LeaseTransaction {
+        recipient: "alias:0:lunes".parse().unwrap(),
+        ..tx.clone()
+    };
+
+    assert_eq!(tx.violations(&rules(mainnet)), []);
+    assert_eq!(
+        tx.violations(&rules(testnet)),
+        [TransactionError::InvalidRecipient(
+            "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string()
+        )]
+    );
+    assert_eq!(
+        short.violations(&rules(mainnet)),
+        [TransactionError::InvalidRecipient("6G".to_string())]
+    );
+    assert_eq!(alias.violations(&rules(testnet)), []);
+    assert_eq!(
+        alias.violations(&rules(mainnet)),
+        [TransactionError::InvalidRecipient(
+            "alias:0:lunes".to_string()
+        )]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn lease_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = lease(pubk.clone());
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk.clone(), Network::Mainnet).unwrap();
+    let exported = sign_lease(
+        prvk.clone(),
+        Network::Mainnet,
+        pubk.clone(),
+        tx.recipient.to_string(),
+        tx.amount,
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(signed[..body.len()], body);
+    assert_eq!(exported[..body.len()], body);
+    assert_eq!(
+        true,
+        validate_signature(pubk.clone(), body.clone(), signed[body.len()..].to_vec())
+    );
+    assert_eq!(
+        tx.sign(prvk.clone(), Network::Testnet),
+        Err(TransactionError::InvalidRecipient(tx.recipient.to_string()))
+    );
+    assert_eq!(
+        sign_lease_js(
+            prvk,
+            Network::Testnet.chain_id(),
+            pubk,
+            tx.recipient.to_string(),
+            tx.amount,
+            tx.fee,
+            tx.timestamp,
+        ),
+        Err(TransactionError::InvalidRecipient(tx.recipient.to_string()).into())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn lease_cancel_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = LeaseCancelTransaction {
+        sender_public_key: pubk.clone(),
+        lease_id: lease(pubk.clone()).id_bytes().unwrap(),
+        fee: 100000,
+        timestamp: 1528077700000,
+    };
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk.clone()).unwrap();
+    let exported = sign_lease_cancel(
+        prvk,
+        pubk.clone(),
+        tx.lease_id.clone(),
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(
+        body,
+        [
+            vec![9],
+            pubk.clone(),
+            vec![0, 0, 0, 0, 0, 1, 134, 160],
+            vec![0, 0, 1, 99, 200, 135, 75, 160],
+            lease(pubk.clone()).id_bytes().unwrap(),
+        ]
+        .concat()
+    );
+    assert_eq!(exported[..body.len()], body);
+    assert_eq!(
+        true,
+        validate_signature(pubk, body.clone(), signed[body.len()..].to_vec())
+    );
+}
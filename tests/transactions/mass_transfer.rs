@@ -0,0 +1,194 @@
+use lunesrs::error::LunesError;
+use lunesrs::network::Network;
+use lunesrs::transactions::fee::suggest_fee;
+use lunesrs::transactions::mass_transfer::{
+    sign_mass_transfer, sign_mass_transfer_js, MassTransferTransaction, Transfer,
+};
+use lunesrs::transactions::recipient::Recipient;
+use lunesrs::transactions::validation::{Rules, Validate};
+use lunesrs::transactions::Transaction;
+use lunesrs::transactions::TransactionError;
+use lunesrs::utils::base58::b58_to_vec;
+use lunesrs::wallet::assembly::{hidden_seed, to_address, to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn recipients(count: u32) -> Vec<Recipient> {
+    (0..count)
+        .map(|nonce| {
+            let prvk = to_private_key(hidden_seed(nonce, "lunes".to_string()));
+            Recipient::Address(to_address(1, 1, to_public_key(prvk)))
+        })
+        .collect()
+}
+
+fn mass_transfer(
+    sender_public_key: Vec<u8>,
+    recipients: Vec<Recipient>,
+) -> MassTransferTransaction {
+    MassTransferTransaction {
+        sender_public_key,
+        asset_id: None,
+        fee: 100000 + 50000 * recipients.len() as u64,
+        transfers: recipients
+            .into_iter()
+            .map(|recipient| Transfer {
+                recipient,
+                amount: 1000,
+            })
+            .collect(),
+        timestamp: 1528077600000,
+        attachment: vec![],
+    }
+}
+
+fn rules(chain_id: u8) -> Rules {
+    Rules::new(chain_id, 1528077600000)
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn mass_transfer_body_bytes() {
+    let addr = b58_to_vec("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string());
+    let tx = mass_transfer(vec![7; 32], vec![Recipient::Address(addr.clone())]);
+
+    assert_eq!(
+        tx.body_bytes().unwrap(),
+        [
+            vec![11, 1],
+            vec![7; 32],
+            vec![0],
+            vec![0, 1],
+            addr,
+            vec![0, 0, 0, 0, 0, 0, 3, 232],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+            vec![0, 0, 0, 0, 0, 2, 73, 240],
+            vec![0, 0],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn mass_transfer_fee() {
+    assert_eq!(
+        suggest_fee(&mass_transfer(vec![7; 32], recipients(0))),
+        100000
+    );
+    assert_eq!(
+        suggest_fee(&mass_transfer(vec![7; 32], recipients(1))),
+        150000
+    );
+    assert_eq!(
+        suggest_fee(&mass_transfer(vec![7; 32], recipients(100))),
+        5100000
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn mass_transfer_validation() {
+    let mainnet = 1;
+    let testnet = 0;
+    let addrs = recipients(3);
+    let tx = mass_transfer(vec![7; 32], addrs.clone());
+    let duplicated = mass_transfer(
+        vec![7; 32],
+        [addrs.clone(), vec![addrs[1].clone()]].concat(),
+    );
+    let too_many = mass_transfer(vec![7; 32], vec![addrs[0].clone(); 101]);
+
+    assert_eq!(tx.violations(&rules(mainnet)), []);
+    assert_eq!(
+        tx.violations(&rules(testnet)),
+        addrs
+            .iter()
+            .map(|addr| TransactionError::InvalidRecipient(addr.to_string()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        duplicated.violations(&rules(mainnet)),
+        [TransactionError::DuplicateRecipient(addrs[1].to_string())]
+    );
+    assert_eq!(
+        too_many.violations(&rules(mainnet)),
+        [TransactionError::TooManyTransfers(101)]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn mass_transfer_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let addrs = recipients(3);
+    let tx = mass_transfer(pubk.clone(), addrs.clone());
+    let duplicated = mass_transfer(pubk.clone(), vec![addrs[0].clone(); 2]);
+    let too_many = mass_transfer(pubk.clone(), vec![addrs[0].clone(); 101]);
+    let body = tx.body_bytes().unwrap();
+    let signed = tx.sign(prvk.clone(), Network::Mainnet).unwrap();
+
+    assert_eq!(signed[..body.len()], body);
+    assert_eq!(signed[body.len()..body.len() + 5], [1, 0, 1, 0, 64]);
+    assert_eq!(
+        true,
+        validate_signature(pubk, body.clone(), signed[body.len() + 5..].to_vec())
+    );
+    assert_eq!(
+        tx.sign(prvk.clone(), Network::Testnet),
+        Err(TransactionError::InvalidRecipient(addrs[0].to_string()))
+    );
+    assert_eq!(
+        duplicated.sign(prvk.clone(), Network::Mainnet),
+        Err(TransactionError::DuplicateRecipient(addrs[0].to_string()))
+    );
+    assert_eq!(
+        too_many.sign(prvk, Network::Mainnet),
+        Err(TransactionError::TooManyTransfers(101))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn mass_transfer_sign_export() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let addrs = [recipients(2), vec!["alias:1:lunes".parse().unwrap()]].concat();
+    let tx = mass_transfer(pubk.clone(), addrs.clone());
+    let signed = sign_mass_transfer(
+        prvk.clone(),
+        Network::Mainnet,
+        pubk.clone(),
+        vec![],
+        addrs.iter().map(|x| x.to_string()).collect(),
+        vec![1000; 3],
+        tx.timestamp,
+        vec![],
+    )
+    .unwrap();
+    let exported_js = sign_mass_transfer_js(
+        prvk,
+        Network::Testnet.chain_id(),
+        pubk,
+        vec![],
+        addrs.iter().map(|x| x.to_string()).collect(),
+        vec![1000; 3],
+        tx.timestamp,
+        vec![],
+    );
+
+    assert_eq!(
+        signed[..tx.body_bytes().unwrap().len()],
+        tx.body_bytes().unwrap()
+    );
+    assert_eq!(
+        true,
+        matches!(
+            exported_js,
+            Err(LunesError::Transaction(TransactionError::InvalidRecipient(
+                _
+            )))
+        )
+    );
+}
@@ -0,0 +1,15 @@
+mod alias;
+mod burn;
+mod data;
+mod fee;
+mod id;
+mod issue;
+#[cfg(feature = "serde")]
+mod json;
+mod lease;
+mod mass_transfer;
+mod parser;
+mod recipient;
+mod reissue;
+mod transfer;
+mod validation;
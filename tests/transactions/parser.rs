@@ -0,0 +1,249 @@
+use lunesrs::network::Network;
+use lunesrs::transactions::alias::{Alias, CreateAliasTransaction};
+use lunesrs::transactions::burn::BurnTransaction;
+use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::issue::IssueTransaction;
+use lunesrs::transactions::lease::{LeaseCancelTransaction, LeaseTransaction};
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+use lunesrs::transactions::parser::{parse_transaction, verify_transaction, TransactionKind};
+use lunesrs::transactions::reissue::ReissueTransaction;
+use lunesrs::transactions::transfer::TransferTransaction;
+use lunesrs::transactions::{Transaction, TransactionError};
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn keys() -> (Vec<u8>, Vec<u8>) {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    (prvk, pubk)
+}
+
+fn transfer(sender_public_key: Vec<u8>) -> TransferTransaction {
+    TransferTransaction {
+        sender_public_key,
+        recipient: "alias:1:lunes".parse().unwrap(),
+        asset_id: Some(vec![2; 32]),
+        fee_asset: None,
+        amount: 100000000,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: "Lunes".as_bytes().to_vec(),
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn parse_every_kind() {
+    let (prvk, pubk) = keys();
+    let addr = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj";
+    let issue = IssueTransaction {
+        sender_public_key: pubk.clone(),
+        name: "Lunes".to_string(),
+        description: "Token".to_string(),
+        quantity: 1000,
+        decimals: 2,
+        reissuable: true,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    };
+    let reissue = ReissueTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: vec![2; 32],
+        quantity: 1000,
+        reissuable: false,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    };
+    let burn = BurnTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: vec![2; 32],
+        amount: 1000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let lease = LeaseTransaction {
+        sender_public_key: pubk.clone(),
+        recipient: addr.parse().unwrap(),
+        amount: 100000000,
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let lease_cancel = LeaseCancelTransaction {
+        sender_public_key: pubk.clone(),
+        lease_id: lease.id_bytes().unwrap(),
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let create_alias = CreateAliasTransaction {
+        sender_public_key: pubk.clone(),
+        alias: Alias {
+            chain_id: 0,
+            name: "lunes".to_string(),
+        },
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let mass_transfer = MassTransferTransaction {
+        sender_public_key: pubk.clone(),
+        asset_id: None,
+        transfers: vec![
+            Transfer {
+                recipient: addr.parse().unwrap(),
+                amount: 1,
+            },
+            Transfer {
+                recipient: "alias:1:lunes".parse().unwrap(),
+                amount: 2,
+            },
+        ],
+        fee: 200000,
+        timestamp: 1528077600000,
+        attachment: vec![1, 2, 3],
+    };
+    let data = DataTransaction {
+        sender_public_key: pubk.clone(),
+        entries: vec![DataEntry {
+            key: "score".to_string(),
+            value: DataValue::Integer(10),
+        }],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+
+    let cases = vec![
+        (
+            issue.sign(prvk.clone()).unwrap(),
+            TransactionKind::Issue(issue),
+        ),
+        (
+            transfer(pubk.clone()).sign(prvk.clone()).unwrap(),
+            TransactionKind::Transfer(transfer(pubk.clone())),
+        ),
+        (
+            reissue.sign(prvk.clone()).unwrap(),
+            TransactionKind::Reissue(reissue),
+        ),
+        (
+            burn.sign(prvk.clone()).unwrap(),
+            TransactionKind::Burn(burn),
+        ),
+        (
+            lease.sign(prvk.clone(), Network::Mainnet).unwrap(),
+            TransactionKind::Lease(lease),
+        ),
+        (
+            lease_cancel.sign(prvk.clone()).unwrap(),
+            TransactionKind::LeaseCancel(lease_cancel),
+        ),
+        (
+            create_alias.sign(prvk.clone()).unwrap(),
+            TransactionKind::CreateAlias(create_alias),
+        ),
+        (
+            mass_transfer.sign(prvk.clone(), Network::Mainnet).unwrap(),
+            TransactionKind::MassTransfer(mass_transfer),
+        ),
+        (data.sign(prvk).unwrap(), TransactionKind::Data(data)),
+    ];
+
+    for (signed, expected) in cases {
+        let parsed = parse_transaction(signed.clone()).unwrap();
+
+        assert_eq!(parsed.transaction, expected);
+        assert_eq!(
+            parsed.transaction.type_id(),
+            expected.body_bytes().unwrap()[0]
+        );
+        assert_eq!(parsed.transaction.sender_public_key(), pubk);
+        assert_eq!(parsed.signed_bytes().unwrap(), signed);
+        assert_eq!(parsed.id().unwrap(), expected.id().unwrap());
+        assert_eq!(true, verify_transaction(signed));
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn parse_malformed_bytes() {
+    let (prvk, pubk) = keys();
+    let signed = transfer(pubk).sign(prvk).unwrap();
+    let mut bad_asset = signed.clone();
+    bad_asset[98] = 3;
+
+    assert_eq!(
+        parse_transaction(vec![]),
+        Err(TransactionError::UnexpectedEnd(0))
+    );
+    assert_eq!(
+        parse_transaction(signed[..120].to_vec()),
+        Err(TransactionError::UnexpectedEnd(99))
+    );
+    assert_eq!(
+        parse_transaction([signed.clone(), vec![0]].concat()),
+        Err(TransactionError::TrailingBytes(signed.len()))
+    );
+    assert_eq!(
+        parse_transaction([vec![7], signed[1..].to_vec()].concat()),
+        Err(TransactionError::InvalidType(7))
+    );
+    assert_eq!(
+        parse_transaction(
+            [
+                vec![4],
+                signed[1..65].to_vec(),
+                vec![5],
+                signed[66..].to_vec()
+            ]
+            .concat()
+        ),
+        Err(TransactionError::InvalidType(5))
+    );
+    assert_eq!(
+        parse_transaction(bad_asset),
+        Err(TransactionError::InvalidBoolean(3))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn parse_invalid_signature() {
+    let (prvk, pubk) = keys();
+    let tx = transfer(pubk);
+    let signed = tx.sign(prvk).unwrap();
+    let tampered = TransferTransaction { amount: 1, ..tx }
+        .signed_bytes(signed[1..65].to_vec())
+        .unwrap();
+
+    assert_eq!(
+        parse_transaction(tampered.clone()),
+        Err(TransactionError::InvalidSignature)
+    );
+    assert_eq!(false, verify_transaction(tampered));
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn parse_invalid_proofs() {
+    let (prvk, pubk) = keys();
+    let data = DataTransaction {
+        sender_public_key: pubk,
+        entries: vec![],
+        fee: 100000,
+        timestamp: 1528077600000,
+    };
+    let body = data.body_bytes().unwrap();
+    let signed = data.sign(prvk).unwrap();
+    let proofs = signed[body.len()..].to_vec();
+
+    assert_eq!(
+        parse_transaction([body.clone(), vec![2], proofs[1..].to_vec()].concat()),
+        Err(TransactionError::InvalidVersion(2))
+    );
+    assert_eq!(
+        parse_transaction([body.clone(), vec![1, 0, 2], proofs[3..].to_vec()].concat()),
+        Err(TransactionError::InvalidProofs(2))
+    );
+    assert_eq!(
+        parse_transaction([body, vec![1, 0, 1, 0, 1, 0]].concat()),
+        Err(TransactionError::InvalidSignature)
+    );
+}
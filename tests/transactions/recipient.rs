@@ -0,0 +1,86 @@
+use lunesrs::transactions::alias::Alias;
+use lunesrs::transactions::recipient::Recipient;
+use lunesrs::transactions::TransactionError;
+use lunesrs::utils::base58::b58_to_vec;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn recipient_parse() {
+    let addr = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj";
+
+    assert_eq!(
+        addr.parse::<Recipient>(),
+        Ok(Recipient::Address(b58_to_vec(addr.to_string())))
+    );
+    assert_eq!(
+        "alias:0:lunes".parse::<Recipient>(),
+        Ok(Recipient::Alias(Alias {
+            chain_id: 0,
+            name: "lunes".to_string()
+        }))
+    );
+    assert_eq!(
+        "alias:D:lunes".parse::<Recipient>(),
+        Ok(Recipient::Alias(Alias {
+            chain_id: b'D',
+            name: "lunes".to_string()
+        }))
+    );
+    for invalid in [
+        "alias:lunes",
+        "alias:10:lunes",
+        "alias:-:lunes",
+        "0OIl",
+        "",
+        "37o7aY3eZZTX",
+    ] {
+        assert_eq!(
+            invalid.parse::<Recipient>(),
+            Err(TransactionError::InvalidRecipient(invalid.to_string()))
+        );
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn recipient_display_and_bytes() {
+    let addr = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj";
+    let alias: Recipient = "alias:1:lunes".parse().unwrap();
+
+    assert_eq!(addr.parse::<Recipient>().unwrap().to_string(), addr);
+    assert_eq!(
+        addr.parse::<Recipient>().unwrap().to_bytes(),
+        Ok(b58_to_vec(addr.to_string()))
+    );
+    assert_eq!(alias.to_string(), "alias:1:lunes");
+    assert_eq!(
+        alias.to_bytes(),
+        Ok(vec![2, 49, 0, 5, 108, 117, 110, 101, 115])
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn recipient_validation() {
+    let mainnet = 1;
+    let testnet = 0;
+    let addr: Recipient = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap();
+    let alias: Recipient = "alias:1:lunes".parse().unwrap();
+    let bad_alias: Recipient = "alias:1:LUNES".parse().unwrap();
+
+    assert_eq!(addr.validate(mainnet), Ok(()));
+    assert_eq!(alias.validate(mainnet), Ok(()));
+    assert_eq!(
+        addr.validate(testnet),
+        Err(TransactionError::InvalidRecipient(addr.to_string()))
+    );
+    assert_eq!(
+        alias.validate(testnet),
+        Err(TransactionError::InvalidRecipient(alias.to_string()))
+    );
+    assert_eq!(
+        bad_alias.validate(mainnet),
+        Err(TransactionError::InvalidAlias("LUNES".to_string()))
+    );
+}
@@ -0,0 +1,82 @@
+use lunesrs::error::LunesError;
+use lunesrs::transactions::reissue::{sign_reissue, ReissueTransaction};
+use lunesrs::transactions::{Transaction, TransactionError};
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn reissue(sender_public_key: Vec<u8>) -> ReissueTransaction {
+    ReissueTransaction {
+        sender_public_key,
+        asset_id: vec![2; 32],
+        quantity: 1000,
+        reissuable: false,
+        fee: 100000000,
+        timestamp: 1528077600000,
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn reissue_body_bytes() {
+    assert_eq!(
+        reissue(vec![7; 32]).body_bytes().unwrap(),
+        [
+            vec![5],
+            vec![7; 32],
+            vec![2; 32],
+            vec![0, 0, 0, 0, 0, 0, 3, 232],
+            vec![0],
+            vec![0, 0, 0, 0, 5, 245, 225, 0],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn reissue_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = reissue(pubk.clone());
+    let signed = tx.sign(prvk.clone()).unwrap();
+    let exported = sign_reissue(
+        prvk,
+        pubk.clone(),
+        tx.asset_id.clone(),
+        tx.quantity,
+        tx.reissuable,
+        tx.fee,
+        tx.timestamp,
+    )
+    .unwrap();
+
+    assert_eq!(signed[0], 5);
+    assert_eq!(signed[65..], tx.body_bytes().unwrap());
+    assert_eq!(exported[65..], tx.body_bytes().unwrap());
+    assert_eq!(
+        true,
+        validate_signature(pubk, tx.body_bytes().unwrap(), signed[1..65].to_vec())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn reissue_invalid_asset_id() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = ReissueTransaction {
+        asset_id: vec![2; 31],
+        ..reissue(pubk.clone())
+    };
+
+    assert_eq!(
+        tx.sign(prvk.clone()),
+        Err(TransactionError::InvalidAssetId(31))
+    );
+    assert_eq!(
+        sign_reissue(prvk, pubk, vec![], 1000, false, 100000000, 1528077600000),
+        Err(LunesError::Transaction(TransactionError::InvalidAssetId(0)))
+    );
+}
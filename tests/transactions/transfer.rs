@@ -0,0 +1,153 @@
+use lunesrs::error::LunesError;
+use lunesrs::transactions::transfer::{sign_transfer, TransferTransaction};
+use lunesrs::transactions::{Transaction, TransactionError};
+use lunesrs::utils::base58::b58_to_vec;
+use lunesrs::wallet::assembly::{to_private_key, to_public_key};
+use lunesrs::wallet::signatures::validate_signature;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn transfer(sender_public_key: Vec<u8>) -> TransferTransaction {
+    TransferTransaction {
+        sender_public_key,
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: None,
+        fee_asset: None,
+        amount: 100000000,
+        fee: 1000000,
+        timestamp: 1528077600000,
+        attachment: "Lunes".as_bytes().to_vec(),
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_body_bytes() {
+    let tx = transfer(vec![7; 32]);
+
+    assert_eq!(
+        tx.body_bytes().unwrap(),
+        [
+            vec![4],
+            vec![7; 32],
+            vec![0],
+            vec![0],
+            vec![0, 0, 1, 99, 200, 133, 197, 0],
+            vec![0, 0, 0, 0, 5, 245, 225, 0],
+            vec![0, 0, 0, 0, 0, 15, 66, 64],
+            b58_to_vec("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string()),
+            vec![0, 5, 76, 117, 110, 101, 115],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_body_bytes_with_assets() {
+    let tx = TransferTransaction {
+        asset_id: Some(vec![2; 32]),
+        fee_asset: Some(vec![3; 32]),
+        ..transfer(vec![7; 32])
+    };
+    let body = tx.body_bytes().unwrap();
+
+    assert_eq!(body[33..66], [vec![1], vec![2; 32]].concat());
+    assert_eq!(body[66..99], [vec![1], vec![3; 32]].concat());
+    assert_eq!(
+        body.len(),
+        transfer(vec![7; 32]).body_bytes().unwrap().len() + 64
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_sign() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = transfer(pubk.clone());
+    let signed = tx.sign(prvk).unwrap();
+
+    assert_eq!(signed[0], 4);
+    assert_eq!(signed[65..], tx.body_bytes().unwrap());
+    assert_eq!(
+        true,
+        validate_signature(pubk, tx.body_bytes().unwrap(), signed[1..65].to_vec())
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_sign_export() {
+    let prvk = to_private_key(vec![1; 32]);
+    let pubk = to_public_key(prvk.clone());
+    let tx = transfer(pubk.clone());
+    let signed = sign_transfer(
+        prvk,
+        pubk.clone(),
+        tx.recipient.to_string(),
+        vec![],
+        vec![],
+        tx.amount,
+        tx.fee,
+        tx.timestamp,
+        tx.attachment.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(signed[65..], tx.body_bytes().unwrap());
+    assert_eq!(
+        true,
+        validate_signature(
+            pubk.clone(),
+            tx.body_bytes().unwrap(),
+            signed[1..65].to_vec()
+        )
+    );
+    assert_eq!(
+        sign_transfer(
+            to_private_key(vec![1; 32]),
+            pubk,
+            tx.recipient.to_string(),
+            vec![],
+            vec![],
+            tx.amount,
+            tx.fee,
+            tx.timestamp,
+            vec![0; 65536],
+        ),
+        Err(LunesError::Transaction(TransactionError::ArrayTooLong(
+            65536
+        )))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_attachment_too_long() {
+    let tx = TransferTransaction {
+        attachment: vec![0; 70000],
+        ..transfer(vec![7; 32])
+    };
+
+    assert_eq!(tx.body_bytes(), Err(TransactionError::ArrayTooLong(70000)));
+    assert_eq!(tx.id(), Err(TransactionError::ArrayTooLong(70000)));
+    assert_eq!(
+        tx.sign(to_private_key(vec![1; 32])),
+        Err(TransactionError::ArrayTooLong(70000))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn transfer_to_alias() {
+    let tx = TransferTransaction {
+        recipient: "alias:1:lunes".parse().unwrap(),
+        ..transfer(vec![7; 32])
+    };
+    let body = tx.body_bytes().unwrap();
+
+    assert_eq!(
+        body[body.len() - 16..body.len() - 7],
+        [2, 49, 0, 5, 108, 117, 110, 101, 115]
+    );
+}
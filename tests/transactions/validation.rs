@@ -0,0 +1,288 @@
+use lunesrs::error::LunesError;
+use lunesrs::network::Network;
+use lunesrs::transactions::data::{DataEntry, DataTransaction, DataValue};
+use lunesrs::transactions::issue::IssueTransaction;
+use lunesrs::transactions::lease::LeaseTransaction;
+use lunesrs::transactions::mass_transfer::{MassTransferTransaction, Transfer};
+use lunesrs::transactions::recipient::Recipient;
+use lunesrs::transactions::transfer::{sign_transfer, TransferTransaction};
+use lunesrs::transactions::validation::{
+    validate_transaction, validate_transaction_js, Rules, Validate, Violations,
+};
+use lunesrs::transactions::{TransactionError, MAX_AMOUNT, MAX_TIMESTAMP_PAST};
+use lunesrs::wallet::assembly::{to_address, to_private_key, to_public_key};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const NOW: u64 = 1528077600000;
+
+fn transfer() -> TransferTransaction {
+    TransferTransaction {
+        sender_public_key: vec![7; 32],
+        recipient: "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap(),
+        asset_id: None,
+        fee_asset: None,
+        amount: 100000000,
+        fee: 100000,
+        timestamp: NOW,
+        attachment: vec![],
+    }
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_timestamp_window() {
+    let rules = Rules::new(Network::Mainnet, NOW);
+    let at = |timestamp: u64| TransferTransaction {
+        timestamp,
+        ..transfer()
+    };
+    let narrow = Rules {
+        max_future: 0,
+        ..rules
+    };
+
+    assert_eq!(at(NOW - MAX_TIMESTAMP_PAST).violations(&rules), []);
+    assert_eq!(at(NOW + 90 * 60 * 1000).violations(&rules), []);
+    assert_eq!(
+        at(NOW - MAX_TIMESTAMP_PAST - 1).violations(&rules),
+        [TransactionError::InvalidTimestamp(
+            NOW - MAX_TIMESTAMP_PAST - 1
+        )]
+    );
+    assert_eq!(
+        at(NOW + 90 * 60 * 1000 + 1).violations(&rules),
+        [TransactionError::InvalidTimestamp(NOW + 90 * 60 * 1000 + 1)]
+    );
+    assert_eq!(
+        at(NOW + 1).violations(&narrow),
+        [TransactionError::InvalidTimestamp(NOW + 1)]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_collects_every_violation() {
+    let testnet = Rules::new(Network::Testnet, NOW);
+    let tx = TransferTransaction {
+        amount: MAX_AMOUNT + 1,
+        fee: 0,
+        timestamp: 0,
+        attachment: vec![0; 141],
+        ..transfer()
+    };
+    let violations = tx.validate(&testnet).unwrap_err();
+
+    assert_eq!(
+        violations,
+        Violations(vec![
+            TransactionError::InvalidTimestamp(0),
+            TransactionError::ZeroFee,
+            TransactionError::AmountOverflow,
+            TransactionError::AttachmentTooLong(141),
+            TransactionError::InvalidRecipient("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string()),
+        ])
+    );
+    assert_eq!(true, violations.contains(&TransactionError::ZeroFee));
+    assert_eq!(
+        TransferTransaction {
+            amount: 0,
+            fee: MAX_AMOUNT + 1,
+            ..transfer()
+        }
+        .violations(&Rules::new(Network::Mainnet, NOW)),
+        [TransactionError::FeeOverflow, TransactionError::ZeroAmount]
+    );
+    assert_eq!(true, transfer().validate(&Rules::new(1, NOW)).is_ok());
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_issue_lengths() {
+    let tx = IssueTransaction {
+        sender_public_key: vec![7; 32],
+        name: "Lun".to_string(),
+        description: "x".repeat(1001),
+        quantity: 0,
+        decimals: 9,
+        reissuable: false,
+        fee: 100000000,
+        timestamp: NOW,
+    };
+
+    assert_eq!(
+        tx.violations(&Rules::new(Network::Mainnet, NOW)),
+        [
+            TransactionError::ZeroAmount,
+            TransactionError::InvalidNameLength(3),
+            TransactionError::InvalidDescriptionLength(1001),
+            TransactionError::InvalidDecimals(9),
+        ]
+    );
+    assert_eq!(
+        tx.validate(&Rules::new(Network::Mainnet, NOW))
+            .map_err(|violations| violations.0.len()),
+        Err(4)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_zero_quantities() {
+    use lunesrs::transactions::burn::BurnTransaction;
+    use lunesrs::transactions::reissue::ReissueTransaction;
+
+    let rules = Rules::new(Network::Mainnet, NOW);
+    let reissue = ReissueTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: vec![2; 32],
+        quantity: 0,
+        reissuable: true,
+        fee: 100000000,
+        timestamp: NOW,
+    };
+    let burn = BurnTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: vec![2; 32],
+        amount: 0,
+        fee: 100000,
+        timestamp: NOW,
+    };
+
+    assert_eq!(reissue.violations(&rules), [TransactionError::ZeroAmount]);
+    assert_eq!(burn.violations(&rules), []);
+    assert_eq!(
+        BurnTransaction {
+            asset_id: vec![],
+            ..burn
+        }
+        .violations(&rules),
+        [TransactionError::InvalidAssetId(0)]
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_lease_to_self() {
+    let pubk = to_public_key(to_private_key(vec![1; 32]));
+    let lease = |recipient: Recipient| LeaseTransaction {
+        sender_public_key: pubk.clone(),
+        recipient,
+        amount: 100000000,
+        fee: 100000,
+        timestamp: NOW,
+    };
+    let own = lease(Recipient::Address(to_address(1, 1, pubk.clone())));
+
+    assert_eq!(
+        own.violations(&Rules::new(Network::Mainnet, NOW)),
+        [TransactionError::LeaseToSelf]
+    );
+    assert_eq!(
+        own.violations(&Rules::new(Network::Testnet, NOW)),
+        [TransactionError::InvalidRecipient(
+            own.recipient.to_string()
+        )]
+    );
+    assert_eq!(
+        lease("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap())
+            .violations(&Rules::new(Network::Mainnet, NOW)),
+        []
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_mass_transfer_and_data() {
+    let mainnet = Rules::new(Network::Mainnet, NOW);
+    let addr: Recipient = "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".parse().unwrap();
+    let mass = MassTransferTransaction {
+        sender_public_key: vec![7; 32],
+        asset_id: None,
+        transfers: vec![
+            Transfer {
+                recipient: addr.clone(),
+                amount: MAX_AMOUNT,
+            },
+            Transfer {
+                recipient: addr.clone(),
+                amount: 1,
+            },
+        ],
+        fee: 200000,
+        timestamp: NOW,
+        attachment: vec![],
+    };
+    let data = DataTransaction {
+        sender_public_key: vec![7; 32],
+        entries: vec![
+            DataEntry {
+                key: String::new(),
+                value: DataValue::Integer(1),
+            },
+            DataEntry {
+                key: String::new(),
+                value: DataValue::Boolean(true),
+            },
+        ],
+        fee: 100000,
+        timestamp: NOW,
+    };
+
+    assert_eq!(
+        mass.violations(&mainnet),
+        [
+            TransactionError::AmountOverflow,
+            TransactionError::DuplicateRecipient(addr.to_string()),
+        ]
+    );
+    assert_eq!(
+        data.violations(&mainnet),
+        [
+            TransactionError::InvalidKeyLength(String::new()),
+            TransactionError::InvalidKeyLength(String::new()),
+            TransactionError::DuplicateKey(String::new()),
+        ]
+    );
+    assert_eq!(
+        data.validate(&mainnet)
+            .map_err(|violations| violations.0.len()),
+        Err(3)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn validation_of_signed_bytes() {
+    let prvk = to_private_key(vec![1; 32]);
+    let signed = sign_transfer(
+        prvk.clone(),
+        to_public_key(prvk),
+        "37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string(),
+        vec![],
+        vec![],
+        1,
+        0,
+        NOW,
+        vec![],
+    )
+    .unwrap();
+
+    assert_eq!(
+        validate_transaction(signed.clone(), Network::Mainnet, NOW),
+        Ok(())
+    );
+    assert_eq!(validate_transaction_js(signed.clone(), 1, NOW), Ok(()));
+    assert_eq!(
+        validate_transaction(signed.clone(), Network::Testnet, NOW + 1),
+        Err(LunesError::Violations(Violations(vec![
+            TransactionError::InvalidRecipient("37o7aY3eZZTXmzrDa5e4Wj3Z4ZZuyV42Aaj".to_string())
+        ])))
+    );
+    assert_eq!(
+        true,
+        validate_transaction(signed, Network::Mainnet, NOW + 3 * MAX_TIMESTAMP_PAST)
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid transaction: timestamp")
+    );
+}
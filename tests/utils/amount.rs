@@ -0,0 +1,118 @@
+use lunesrs::error::LunesError;
+use lunesrs::transactions::MAX_AMOUNT;
+use lunesrs::utils::amount::{Amount, LUNES_DECIMALS};
+use lunesrs::utils::serialize::serialize_integer;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[test]
+#[wasm_bindgen_test]
+fn amount_parse_and_format() {
+    let parse = |text: &str, decimals: u8| Amount::parse(text.to_string(), decimals).unwrap();
+
+    assert_eq!(parse("1.5", LUNES_DECIMALS).units(), 150000000);
+    assert_eq!(parse("1", 8).units(), 100000000);
+    assert_eq!(parse("0.00000001", 8).units(), 1);
+    assert_eq!(parse("007.10", 2).units(), 710);
+    assert_eq!(parse("42", 0).units(), 42);
+    assert_eq!(parse("92233720368.54775807", 8).units(), MAX_AMOUNT);
+    assert_eq!(parse("1.5", 8).to_string(), "1.5");
+    assert_eq!(parse("1.5", 8).to_fixed(), "1.50000000");
+    assert_eq!(parse("2.00", 8).to_string(), "2");
+    assert_eq!(Amount::lunes(0).unwrap().to_string(), "0");
+    assert_eq!(Amount::lunes(0).unwrap().to_fixed(), "0.00000000");
+    assert_eq!(Amount::new(1000, 0).unwrap().to_string(), "1000");
+    assert_eq!(
+        Amount::new(MAX_AMOUNT, 8).unwrap().to_string(),
+        "92233720368.54775807"
+    );
+    assert_eq!(Amount::new(u64::MAX, 8), Err(LunesError::AmountOverflow));
+    assert_eq!(
+        Amount::new(MAX_AMOUNT + 1, 0),
+        Err(LunesError::AmountOverflow)
+    );
+    assert_eq!(
+        Amount::lunes(MAX_AMOUNT + 1),
+        Err(LunesError::AmountOverflow)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn amount_parse_errors() {
+    let parse = |text: &str, decimals: u8| Amount::parse(text.to_string(), decimals);
+
+    for text in [
+        "", ".5", "1.", "-1", "+1", "1e8", " 1", "1,5", "1.2.3", "0x10",
+    ] {
+        assert_eq!(
+            true,
+            matches!(parse(text, 8), Err(LunesError::InvalidAmount(_))),
+            "{:?}",
+            text
+        );
+    }
+    assert_eq!(
+        true,
+        matches!(parse("0.000000001", 8), Err(LunesError::InvalidAmount(_)))
+    );
+    assert_eq!(
+        true,
+        matches!(parse("1.5", 0), Err(LunesError::InvalidAmount(_)))
+    );
+    assert_eq!(
+        true,
+        matches!(parse("1", 9), Err(LunesError::InvalidAmount(_)))
+    );
+    assert_eq!(
+        parse("184467440737.09551616", 8),
+        Err(LunesError::AmountOverflow)
+    );
+    assert_eq!(
+        parse("99999999999999999999", 0),
+        Err(LunesError::AmountOverflow)
+    );
+    assert_eq!(
+        parse("92233720368.54775807", 8).unwrap().units(),
+        MAX_AMOUNT
+    );
+    assert_eq!(
+        parse("92233720368.54775808", 8),
+        Err(LunesError::AmountOverflow)
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn amount_checked_arithmetic() {
+    let one = Amount::lunes(100000000).unwrap();
+    let token = Amount::new(100, 2).unwrap();
+
+    assert_eq!(one.checked_add(&one).unwrap().units(), 200000000);
+    assert_eq!(one.checked_sub(&one).unwrap().units(), 0);
+    assert_eq!(one.checked_mul(3).unwrap().to_string(), "3");
+    assert_eq!(
+        Amount::lunes(1).unwrap().checked_sub(&one),
+        Err(LunesError::AmountOverflow)
+    );
+    assert_eq!(
+        Amount::lunes(MAX_AMOUNT)
+            .unwrap()
+            .checked_add(&Amount::lunes(1).unwrap()),
+        Err(LunesError::AmountOverflow)
+    );
+    assert_eq!(one.checked_mul(u64::MAX), Err(LunesError::AmountOverflow));
+    assert_eq!(
+        one.checked_add(&token),
+        Err(LunesError::MismatchedDecimals(8, 2))
+    );
+}
+
+#[test]
+#[wasm_bindgen_test]
+fn amount_bytes() {
+    let amount = Amount::parse("1528077.6".to_string(), 6).unwrap();
+
+    assert_eq!(amount.to_bytes(), serialize_integer(1528077600000));
+    assert_eq!(amount.to_bytes(), [0, 0, 1, 99, 200, 133, 197, 0]);
+    assert_eq!(u64::from(amount), 1528077600000);
+}
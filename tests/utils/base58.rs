@@ -21,3 +21,22 @@ fn verify_base58_encode() {
 
     assert_eq!(output, "DEiWH5L".to_string());
 }
+
+#[test]
+#[wasm_bindgen_test]
+fn verify_base58_try_decode() {
+    use lunesrs::error::LunesError;
+    use lunesrs::utils::base58::try_b58_to_vec;
+
+    assert_eq!(
+        try_b58_to_vec("DEiWH5L".to_string()),
+        Ok(vec![108, 117, 110, 101, 115])
+    );
+    assert_eq!(
+        true,
+        matches!(
+            try_b58_to_vec("0OIl".to_string()),
+            Err(LunesError::InvalidBase58(_))
+        )
+    );
+}
@@ -38,3 +38,12 @@ fn verify_sha256() {
 
     assert_eq!(output, response);
 }
+
+#[test]
+fn verify_try_blake2b32b() {
+    use lunesrs::utils::crypto::{to_blake2b32b, try_to_blake2b32b};
+
+    let input = "lunes".to_string().as_bytes().to_vec();
+
+    assert_eq!(try_to_blake2b32b(input.clone()), Ok(to_blake2b32b(input)));
+}
@@ -1,3 +1,4 @@
+mod amount;
 mod base58;
 mod crypto;
 mod random;
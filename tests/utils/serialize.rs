@@ -28,3 +28,21 @@ fn verify_string_serialization() {
         ]
     );
 }
+
+#[test]
+#[wasm_bindgen_test]
+fn verify_array_serialization() {
+    use lunesrs::error::LunesError;
+    use lunesrs::utils::serialize::serialize_array;
+
+    assert_eq!(serialize_array(vec![]), Ok(vec![0, 0]));
+    assert_eq!(
+        serialize_array("lunes".as_bytes().to_vec()),
+        Ok(vec![0, 5, 108, 117, 110, 101, 115])
+    );
+    assert_eq!(serialize_array(vec![0; 65535]).unwrap().len(), 65537);
+    assert_eq!(
+        serialize_array(vec![0; 65536]),
+        Err(LunesError::ArrayTooLong(65536))
+    );
+}